@@ -0,0 +1,112 @@
+//! `zordon` errors
+//!
+//! Contains the error type returned by the fallible methods implemented by `zordon` types.
+
+use std::fmt;
+
+/// A specialised [`Result`](std::result::Result) type for `zordon` operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error type returned by the fallible `try_*` methods of `zordon` types.
+///
+/// Errors returned while parsing carry an offset from the start of the buffer being parsed. Errors
+/// returned by the methods of an already parsed view, such as `val` or `set`, carry an offset from
+/// the start of that view, pointing at the offending byte where there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The buffer is too short to hold the view.
+    OutOfBounds {
+        /// Number of bytes required by the view.
+        needed: usize,
+        /// Number of bytes left in the buffer.
+        available: usize,
+        /// Offset of the view from the start of the buffer being parsed.
+        offset: usize,
+    },
+    /// The view holds, or would be set to, an invalid value.
+    Invalid {
+        /// Description of why the value is invalid.
+        reason: &'static str,
+        /// Offset of the first invalid byte, from the start of the buffer being parsed or, for errors
+        /// returned by the methods of a parsed view, from the start of the view.
+        offset: usize,
+    },
+    /// The view does not hold the expected magic value.
+    BadMagic {
+        /// The expected magic value.
+        expected: &'static [u8],
+        /// The bytes held by the view.
+        found: Vec<u8>,
+        /// Offset of the view from the start of the buffer being parsed.
+        offset: usize,
+    },
+    /// The value does not fit in the view.
+    Overflow {
+        /// Size of the view in bytes.
+        size: usize,
+        /// Offset of the view from the start of the buffer being parsed.
+        offset: usize,
+    },
+}
+
+impl Error {
+    /// Returns the offset at which the error occurred.
+    pub fn offset(&self) -> usize {
+        match self {
+            Error::OutOfBounds { offset, .. }
+            | Error::Invalid { offset, .. }
+            | Error::BadMagic { offset, .. }
+            | Error::Overflow { offset, .. } => *offset,
+        }
+    }
+
+    /// Returns the error with `n` added to its offset.
+    ///
+    /// Used when composing views, so that the offset is relative to the outermost buffer.
+    pub fn offset_by(mut self, n: usize) -> Self {
+        match &mut self {
+            Error::OutOfBounds { offset, .. }
+            | Error::Invalid { offset, .. }
+            | Error::BadMagic { offset, .. }
+            | Error::Overflow { offset, .. } => *offset += n,
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfBounds {
+                needed,
+                available,
+                offset,
+            } => write!(
+                f,
+                "view at offset {:#x} needs {} bytes but only {} are available",
+                offset, needed, available
+            ),
+            Error::Invalid { reason, offset } => {
+                write!(f, "invalid view at offset {:#x}: {}", offset, reason)
+            }
+            Error::BadMagic {
+                expected,
+                found,
+                offset,
+            } => write!(
+                f,
+                "bad magic at offset {:#x}: expected {:02x?} but found {:02x?}",
+                offset, expected, found
+            ),
+            Error::Overflow { size, offset } => write!(
+                f,
+                "value does not fit in the {} byte view at offset {:#x}",
+                size, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
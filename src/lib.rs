@@ -10,7 +10,7 @@
 //! `zordon` provides simple low-level abstractions for zero-copy parsing and mutation.
 //!
 //! `zordon` types allow a single mutable u8 buffer to be treated as
-//! a series of u8-u128, i8-i128 or [u8; _] values without the need to copy data in the original buffer. Setting, getting and
+//! a series of u8-u128, i8-i128, f32, f64 or [u8; _] values without the need to copy data in the original buffer. Setting, getting and
 //! adding to the values is transparent via the methods implemented on the calling type.
 //!
 //! The `[MutView]` derive macro can be used on a data structure whose fields use zordon types. Allowing a buffer to be
@@ -18,7 +18,7 @@
 //!
 //! ## Simple example
 //! ```
-//! use mut_view::MutView;
+//! use zordon::MutView;
 //! use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView, ModMulByteView};
 //!
 //! #[derive(MutView)]
@@ -46,7 +46,7 @@
 //!
 //! #### Deriving `mut_view`
 //! ```
-//! # use mut_view::MutView;
+//! # use zordon::MutView;
 //! # use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView, ModMulByteView};
 //!  #[derive(MutView)]
 //!  struct Example<'a> {
@@ -59,14 +59,18 @@
 //!
 //! - `ByteView<'a, u8>` specifies that the underlying data is a single byte value of type [`u8`]
 //! - `MulByteView<'a, u16, LitEnd>` specifies that the underlying data is a little endian two byte
-//! value of type [`u16`].
+//!   value of type [`u16`].
 //!     - `LitEnd` can be swapped with `BigEnd` and the data will be treated as big endian.
-//!     - `u16` can be swapped with u32-u128 or i16-i128
+//!     - `LitEnd` can be swapped with `NativeEnd` to use the byte order of the host. `NetworkEnd` is an alias of `BigEnd`.
+//!     - `LitEnd` can be swapped with [`Endian`](types::Endian) to select the byte order at runtime via `set_endian`.
+//!     - `u16` can be swapped with u32-u128, i16-i128, f32 or f64
 //! - `ArrayView<'a, [u8; 3]>` specifies that the underlying data is three byte value of type [`u8; 3`]
+//!     - Arrays of other primitive types, e.g. `ArrayView<'a, [u16; 3], BigEnd>`, take an endian marker and
+//!       are accessed by index with `get`/`set`.
 //!
 //! #### Instantiating the struct
 //! ```
-//! # use mut_view::MutView;
+//! # use zordon::MutView;
 //! # use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView, ModMulByteView};
 //! # #[derive(MutView)]
 //! # struct Example<'a> {
@@ -96,7 +100,7 @@
 //! - For [`ByteView`] the [`ModByteView`] trait must be in scope.
 //! - For [`MulByteView`] the [`ModMulByteView`] trait must be in scope
 //! ```
-//! use mut_view::MutView;
+//! use zordon::MutView;
 //! use zordon::types::{ByteView, ModByteView};
 //!
 //! #[derive(MutView)]
@@ -119,7 +123,7 @@
 //! Retriving the underlying value for [`ArrayView`] works slightly differently. Rather than returning
 //! the data, a mutable/immutable reference to the data is returned.
 //! ```
-//! # use mut_view::MutView;
+//! # use zordon::MutView;
 //! # use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView, ModMulByteView};
 //! #[derive(MutView)]
 //! struct Example<'a> {
@@ -139,16 +143,103 @@
 //!     assert_eq!(*example.arr_f.as_ref(), [0xAA, 0xBB, 0xCC]);       
 //!     
 //!     // as mutable reference
-//!     {
-//!         let mut m_ref = example.arr_f.as_mut_ref();
-//!         m_ref[0] = 0xFF;
-//!     }    
+//!     example.arr_f.as_mut()[0] = 0xFF;
 //!     assert_eq!(input_buf, [0xFF, 0xBB, 0xCC]);       
 //! }
 //! ```
+//! If the array needs to be shared between multiple owners, [`SharedArrayView`](types::SharedArrayView)
+//! stores the slice behind an `Rc<RefCell<_>>` and hands out clones with `rc_clone`.
+//! ## Fallible parsing
+//! `mut_view` panics when the buffer is too short for the view. Every `zordon` type also provides
+//! `try_mut_view`, which returns an [`Error`] reporting the needed length, the available length and
+//! the offset instead.
+//!
+//! The [`impl_try_mut_view`] macro implements `try_mut_view` for a struct composed of `zordon` types.
+//! ```
+//! use zordon::{impl_try_mut_view, MutView, Error};
+//! use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView};
+//!
+//! impl_try_mut_view! {
+//!     #[derive(MutView)]
+//!     struct Header<'a> {
+//!         u8_f: ByteView<'a, u8>,
+//!         u16_f: MulByteView<'a, u16, BigEnd>,
+//!     }
+//! }
+//!
+//! impl_try_mut_view! {
+//!     #[derive(MutView)]
+//!     struct Example<'a> {
+//!         header: Header<'a>,
+//!         arr_f: ArrayView<'a, [u8; 3]>,
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x00, 0x01, 0x02, 0x03, 0x04];
+//!     let err = Example::try_mut_view(&mut input_buf).err().unwrap();
+//!     assert_eq!(err, Error::OutOfBounds { needed: 3, available: 2, offset: 3 });
+//!
+//!     let mut input_buf = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+//!     let (example, _) = Example::try_mut_view(&mut input_buf).unwrap();
+//!     assert_eq!(example.header.u8_f.val(), 0x00);
+//! }
+//! ```
+//! ## Read-only views
+//! [`ByteRef`](types::ByteRef), [`MulByteRef`](types::MulByteRef) and [`ArrayRef`](types::ArrayRef)
+//! are read-only counterparts of the mutable views, constructed from a `&[u8]` with `ref_view`.
+//! Values are retrieved with `val()`, which requires the [`RefByteView`](types::RefByteView) or
+//! [`RefMulByteView`](types::RefMulByteView) trait to be in scope.
+//!
+//! The [`impl_ref_view`] macro implements `ref_view` for a struct composed of read-only types. A mutable
+//! view can be downgraded to a read-only one with `downgrade` or `into_ref`.
+//! ```
+//! use zordon::types::{ByteView, ByteRef, MulByteRef, BigEnd, ModByteView, RefByteView, RefMulByteView};
+//!
+//! static INPUT: [u8; 3] = [0x00, 0x01, 0x02];
+//!
+//! fn main() {
+//!     let (u8_f, buf): (ByteRef<u8>, _) = ByteRef::ref_view(&INPUT);
+//!     let (u16_f, _): (MulByteRef<u16, BigEnd>, _) = MulByteRef::ref_view(buf);
+//!
+//!     assert_eq!(u8_f.val(), 0x00);
+//!     assert_eq!(u16_f.val(), 0x0102);
+//!
+//!     let mut input_buf = [0x00];
+//!     let (mut u8_f, _): (ByteView<u8>, _) = ByteView::mut_view(&mut input_buf);
+//!     u8_f.set(0xFF);
+//!     assert_eq!(u8_f.downgrade().val(), 0xFF);
+//! }
+//! ```
+//! ## Offsets
+//! Views only hold the slice they were split from, so they don't store their offset in the original
+//! buffer. Instead a [`Root`](types::Root) recording the location of the buffer is created before
+//! parsing, and [`ViewOffset`](types::ViewOffset) works out the offset of any view or struct from it.
+//! Keep the [`Root`](types::Root) alongside the views to report file positions.
+//! ```
+//! use zordon::{impl_view_offset, MutView};
+//! use zordon::types::{ByteView, MulByteView, BigEnd, Root, ViewOffset};
+//!
+//! impl_view_offset! {
+//!     #[derive(MutView)]
+//!     struct Entry<'a> {
+//!         kind: ByteView<'a, u8>,
+//!         len: MulByteView<'a, u16, BigEnd>,
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0xFF, 0x00, 0x01, 0x00];
+//!     let root = Root::new(&input_buf);
+//!     let (entry, _) = Entry::mut_view(&mut input_buf[1..]);
+//!
+//!     assert_eq!(entry.len.offset(root), 2);
+//!     assert_eq!(entry.range(root), 1..4);
+//! }
+//! ```
 //! ## Composite example
 //! ```
-//! use mut_view::MutView;
+//! use zordon::MutView;
 //! use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ModByteView, ModMulByteView};
 //!
 //! #[derive(MutView)]
@@ -188,18 +279,45 @@
 //!
 //! - Zero-copy -- Original buffer is split into mutable slices
 //! - Able to parse the following types: `[u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, [u8; _]]`
-//! - The types u16..u128 and i16..i128 can be treated as little endian or big endian
+//! - Able to parse IEEE-754 floating point types: `[f32, f64]`
+//! - The types u16..u128, i16..i128, f32 and f64 can be treated as little endian or big endian
+//! - The byte order can be selected at runtime for formats that declare it in a header
+//! - Bitfields packed into an integer, numbered from either end, via [`BitfieldView`](types::BitfieldView)
+//! - Enums stored as integers, validating unknown discriminants, via [`EnumView`](types::EnumView)
+//! - Bitmasks of flags with set/clear/toggle operations via [`FlagsView`](types::FlagsView)
+//! - Variable length byte slices whose length comes from an earlier field via [`SliceView`](types::SliceView)
+//! - Tables of back to back records, constructed lazily, via [`SeqView`](types::SeqView)
+//! - Integers whose width differs from the Rust type, such as 24 or 48 bit values, via [`IntView`](types::IntView)
+//! - Arithmetic, bitwise and shift assignment operators (`+=`, `&=`, `<<=`, ...) on [`ByteView`] and [`MulByteView`]
+//! - Checked, wrapping and saturating arithmetic on integer views for safely fixing up length and offset fields
+//! - Views are `Send` and `Sync`, and [`split_views`] splits a buffer into records that can be patched in parallel
+//! - Compile-time sizes of views and structs via [`ViewSize`](types::ViewSize) and [`impl_view_size`]
+//! - A common [`ZordonView`](types::ZordonView) trait for built-in and custom view types, with optional validation
+//! - Offsets of views and structs within the root buffer via [`ViewOffset`](types::ViewOffset) and [`impl_view_offset`]
+//! - Padding, alignment and reserved fields via [`PadView`](types::PadView), [`AlignView`](types::AlignView) and [`ReservedView`](types::ReservedView)
+//! - Magic values and signatures that are checked when parsing via [`MagicView`](types::MagicView) and [`impl_magic`]
+//! - Fixed-size NUL-padded text fields via [`AsciiView`](types::AsciiView), [`Utf8View`](types::Utf8View) and [`Utf16View`](types::Utf16View)
+//! - Integers stored as octal, decimal or hex ASCII text, as in tar, ar and cpio headers, via [`AsciiNumView`](types::AsciiNumView)
+//! - Variable-length integers (LEB128, protobuf and QUIC varints) that can be re-encoded in place via [`VarIntView`](types::VarIntView)
+//! - Checksums (RFC 1071, CRC-32, Adler-32 and PE) that can be verified and recomputed in place via [`ChecksumView`](types::ChecksumView)
 //! - Auto implementation of `mut_view` for structs via the [`MutView`] derive macro.
+//! - Read-only views over a `&[u8]` for buffers that can only be borrowed immutably.
+//! - Fallible `try_mut_view` constructors that return an [`Error`] rather than panicking.
 //!
 //!
 //!
 
+pub mod error;
+pub mod split;
 mod tests;
 pub mod types;
+pub use error::{Error, Result};
 pub use mutview::MutView;
+pub use split::{split_views, try_split_views};
 
 /// Import prelude to get everything needed into scope
 pub mod prelude {
     pub use mutview::MutView;
+    pub use crate::error::Error;
     pub use crate::types::*;
 }
@@ -0,0 +1,78 @@
+//! Helpers for splitting a buffer into disjoint record views.
+//!
+//! Every `zordon` type apart from [`SharedArrayView`](crate::types::SharedArrayView) is `Send` and
+//! `Sync`, so the views returned here can be handed to scoped threads or a thread pool and patched
+//! in parallel.
+
+use crate::error::Result;
+
+/// Splits `buf` into records of `stride` bytes and constructs a view over each of them with `f`.
+///
+/// Returns the views and the leftover slice, which is shorter than `stride`. `f` is typically the
+/// `mut_view` method of a struct deriving [`MutView`](crate::MutView).
+///
+/// ```
+/// use zordon::{split_views, MutView};
+/// use zordon::types::{MulByteView, LitEnd, ModMulByteView};
+///
+/// #[derive(MutView)]
+/// struct Record<'a> {
+///     id: MulByteView<'a, u16, LitEnd>,
+///     count: MulByteView<'a, u16, LitEnd>,
+/// }
+///
+/// let mut input_buf = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+/// let (records, _) = split_views(&mut input_buf, 4, Record::mut_view);
+///
+/// std::thread::scope(|s| {
+///     for mut r in records {
+///         s.spawn(move || r.count.set(r.id.val() * 10));
+///     }
+/// });
+///
+/// assert_eq!(input_buf, [0x01, 0x00, 0x0A, 0x00, 0x02, 0x00, 0x14, 0x00]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `stride` is 0, or if `f` panics because a record is too short for the view.
+pub fn split_views<'a, V, F>(buf: &'a mut [u8], stride: usize, mut f: F) -> (Vec<V>, &'a mut [u8])
+where
+    F: FnMut(&'a mut [u8]) -> (V, &'a mut [u8]),
+{
+    let mut chunks = buf.chunks_exact_mut(stride);
+    let views = chunks.by_ref().map(|chunk| f(chunk).0).collect();
+
+    (views, chunks.into_remainder())
+}
+
+/// Fallible version of [`split_views`], where `f` is typically the `try_mut_view` method of a struct.
+///
+/// # Errors
+///
+/// Returns the first error returned by `f`, with its offset relative to the start of `buf`.
+///
+/// # Panics
+///
+/// Panics if `stride` is 0.
+pub fn try_split_views<'a, V, F>(
+    buf: &'a mut [u8],
+    stride: usize,
+    mut f: F,
+) -> Result<(Vec<V>, &'a mut [u8])>
+where
+    F: FnMut(&'a mut [u8]) -> Result<(V, &'a mut [u8])>,
+{
+    let mut chunks = buf.chunks_exact_mut(stride);
+    let views = chunks
+        .by_ref()
+        .enumerate()
+        .map(|(i, chunk)| {
+            f(chunk)
+                .map(|(view, _)| view)
+                .map_err(|e| e.offset_by(i * stride))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((views, chunks.into_remainder()))
+}
@@ -1,9 +1,6 @@
 #[allow(unused_imports)]
 use crate::{types::*, MutView};
-#[allow(unused_attributes)]
-#[macro_use]
 #[allow(unused_imports)]
-#[macro_use]
 use assert_hex::assert_eq_hex;
 
 #[cfg(test)]
@@ -15,12 +12,14 @@ struct LitEndUnsignTest<'a> {
     pub unsigned_128: MulByteView<'a, u128, LitEnd>,
 }
 
-#[derive(MutView)]
-struct BigEndUnsignTest<'a> {
-    pub unsigned_16: MulByteView<'a, u16, BigEnd>,
-    pub unsigned_32: MulByteView<'a, u32, BigEnd>,
-    pub unsigned_64: MulByteView<'a, u64, BigEnd>,
-    pub unsigned_128: MulByteView<'a, u128, BigEnd>,
+crate::impl_try_mut_view! {
+    #[derive(MutView)]
+    struct BigEndUnsignTest<'a> {
+        pub unsigned_16: MulByteView<'a, u16, BigEnd>,
+        pub unsigned_32: MulByteView<'a, u32, BigEnd>,
+        pub unsigned_64: MulByteView<'a, u64, BigEnd>,
+        pub unsigned_128: MulByteView<'a, u128, BigEnd>,
+    }
 }
 #[derive(MutView)]
 struct LitEndSignTest<'a> {
@@ -159,7 +158,7 @@ fn mulbyteval_set() {
 #[test]
 fn arrayval_deref() {
     let arr = [0x1, 0x2, 0x3, 0x4];
-    let mut buf = arr.clone();
+    let mut buf = arr;
     let (t, _): (ArrayView<[u8; 4]>, _) = ArrayView::mut_view(&mut buf);
 
     assert_eq_hex!(*t.as_ref(), arr);
@@ -168,13 +167,27 @@ fn arrayval_deref() {
 #[test]
 fn arrayval_deref_mut() {
     let mut buf = [0];
-    let (t, _): (ArrayView<[u8; 1]>, _) = ArrayView::mut_view(&mut buf);
+    let (mut t, _): (ArrayView<[u8; 1]>, _) = ArrayView::mut_view(&mut buf);
 
-    t.as_mut_ref()[0] = 0xA;
+    t.as_mut()[0] = 0xA;
 
     assert_eq_hex!(*t.as_ref(), [0xA]);
 }
 
+#[test]
+fn shared_arrayval_rc_clone() {
+    let mut buf = [0, 0];
+    let (mut t, _): (SharedArrayView<[u8; 2]>, _) = SharedArrayView::mut_view(&mut buf);
+
+    let shared = t.rc_clone();
+    shared.borrow_mut()[0] = 0xA;
+    t.set(&[0xA, 0xB]);
+    t.as_mut_ref()[1] = 0xC;
+
+    assert_eq_hex!(**t.as_ref(), [0xA, 0xC]);
+    assert_eq_hex!(**shared.borrow(), [0xA, 0xC]);
+}
+
 #[test]
 fn arrayval_set() {
     let mut buf = vec![0; 4];
@@ -208,3 +221,1441 @@ impl_mulbyteval_assign_test!(mulbyte_val_addassign, +=, [4, 2, 4, 2, 2, 2, 4, 2,
 impl_mulbyteval_assign_test!(mulbyte_val_subassign, -=, [0, 2, 0, 2, 2, 2, 0, 2, 2, 2, 2, 2, 2, 2]);
 impl_mulbyteval_assign_test!(mulbyte_val_mulassign, *=, [4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4]);
 impl_mulbyteval_assign_test!(mulbyte_val_divassign, /=, [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+impl_mulbyteval_assign_test!(mulbyte_val_remassign, %=, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+impl_mulbyteval_assign_test!(mulbyte_val_bitandassign, &=, [2, 0, 2, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+impl_mulbyteval_assign_test!(mulbyte_val_bitorassign, |=, [2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+impl_mulbyteval_assign_test!(mulbyte_val_bitxorassign, ^=, [0, 2, 0, 2, 2, 2, 0, 2, 2, 2, 2, 2, 2, 2]);
+impl_mulbyteval_assign_test!(mulbyte_val_shlassign, <<=, [8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8]);
+impl_mulbyteval_assign_test!(mulbyte_val_shrassign, >>=, [0x80, 0, 0x80, 0x80, 0x80, 0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0]);
+
+#[test]
+fn try_mut_view_out_of_bounds() {
+    let mut buf = vec![0; 3];
+
+    let r: crate::Result<(MulByteView<u32, LitEnd>, _)> = MulByteView::try_mut_view(&mut buf);
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 4,
+            available: 3,
+            offset: 0
+        })
+    );
+
+    let r: crate::Result<(ArrayView<[u8; 4]>, _)> = ArrayView::try_mut_view(&mut buf);
+    assert!(r.is_err());
+
+    let r: crate::Result<(ByteView<u8>, _)> = ByteView::try_mut_view(&mut []);
+    assert!(r.is_err());
+}
+
+#[test]
+fn try_mut_view_ok() {
+    let mut buf = vec![0x1, 0x2, 0x3];
+
+    let (b, leftover): (ByteView<u8>, _) = ByteView::try_mut_view(&mut buf).unwrap();
+    assert_eq!(b.val(), 0x1);
+    assert_eq!(leftover.len(), 2);
+}
+
+#[test]
+fn try_mut_view_struct() {
+    let mut buf = vec![0; 29];
+    let err = BigEndUnsignTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!(
+        err,
+        crate::Error::OutOfBounds {
+            needed: 16,
+            available: 15,
+            offset: 14
+        }
+    );
+    assert_eq!(err.offset(), 14);
+
+    let mut buf = vec![0; 30];
+    let (t, leftover) = BigEndUnsignTest::try_mut_view(&mut buf).unwrap();
+    assert_eq!(t.unsigned_16.val(), 0);
+    assert!(leftover.is_empty());
+}
+
+crate::impl_try_mut_view! {
+    #[derive(MutView)]
+    struct TryMutViewOrderTest<'a> {
+        pub a: ByteView<'a, u8>,
+        pub b: MulByteView<'a, u16, BigEnd>,
+        pub c: Option<u8>,
+    }
+}
+
+#[test]
+fn try_mut_view_struct_order() {
+    let mut buf = vec![0x1, 0x2, 0x3, 0x4];
+    let (t, leftover) = TryMutViewOrderTest::mut_view(&mut buf);
+    assert_eq!(t.a.val(), 0x1);
+    assert_eq_hex!(t.b.val(), 0x0203);
+    assert_eq!(t.c, None);
+    assert_eq!(leftover, [0x4]);
+
+    let (t, leftover) = TryMutViewOrderTest::try_mut_view(&mut buf).unwrap();
+    assert_eq!(t.a.val(), 0x1);
+    assert_eq_hex!(t.b.val(), 0x0203);
+    assert_eq!(t.c, None);
+    assert_eq!(leftover, [0x4]);
+}
+
+#[test]
+fn byteref_val() {
+    let buf = [U8_RESULT];
+
+    let (b, leftover): (ByteRef<u8>, _) = ByteRef::ref_view(&buf);
+    assert_eq!(b.val(), U8_RESULT);
+    assert!(leftover.is_empty());
+
+    let (b, _): (ByteRef<i8>, _) = ByteRef::ref_view(&buf);
+    assert_eq!(b.val(), I8_RESULT);
+}
+
+#[test]
+fn mulbyteref_val() {
+    let buf = U32_BE_RESULT.to_be_bytes();
+
+    let (b, _): (MulByteRef<u32, BigEnd>, _) = MulByteRef::ref_view(&buf);
+    assert_eq_hex!(b.val(), U32_BE_RESULT);
+
+    let (b, _): (MulByteRef<u32, LitEnd>, _) = MulByteRef::ref_view(&buf);
+    assert_eq_hex!(b.val(), U32_BE_RESULT.swap_bytes());
+
+    let r: crate::Result<(MulByteRef<u64, LitEnd>, _)> = MulByteRef::try_ref_view(&buf);
+    assert!(r.is_err());
+}
+
+#[test]
+fn arrayref_as_ref() {
+    let buf = [0x1, 0x2, 0x3, 0x4, 0x5];
+
+    let (t, leftover): (ArrayRef<[u8; 4]>, _) = ArrayRef::ref_view(&buf);
+    assert_eq_hex!(*t.as_ref(), [0x1, 0x2, 0x3, 0x4]);
+    assert_eq_hex!(*leftover, [0x5]);
+}
+
+#[test]
+fn arrayref_typed() {
+    let buf = [0x0, 0x1, 0x0, 0x2, 0xFF];
+
+    let (t, leftover): (ArrayRef<[u16; 2], BigEnd>, _) = ArrayRef::ref_view(&buf);
+    assert_eq!(t.len(), 2);
+    assert_eq_hex!(t.get(1), Some(0x0002));
+    assert_eq!(t.get(2), None);
+    assert_eq_hex!(t.iter().collect::<Vec<_>>(), vec![0x0001, 0x0002]);
+    assert_eq_hex!(*leftover, [0xFF]);
+
+    let (mut t, _): (ArrayRef<[u16; 2], Endian>, _) = ArrayRef::ref_view(&buf);
+    t.set_endian(Endian::Big);
+    let mut dst = [0; 2];
+    t.copy_to(&mut dst);
+    assert_eq_hex!(dst, [0x0001, 0x0002]);
+
+    let mut buf = [0x0, 0x1, 0x0, 0x2];
+    let (a, _): (ArrayView<[u16; 2], BigEnd>, _) = ArrayView::mut_view(&mut buf);
+    assert_eq_hex!(a.downgrade().get(0), Some(0x0001));
+    assert_eq_hex!(a.into_ref().get(1), Some(0x0002));
+}
+
+#[test]
+fn view_downgrade() {
+    let mut buf = vec![0; 30];
+    let (mut t, _) = BigEndUnsignTest::mut_view(&mut buf);
+
+    t.unsigned_16.set(U16_BE_RESULT);
+    assert_eq_hex!(t.unsigned_16.downgrade().val(), U16_BE_RESULT);
+
+    let r = t.unsigned_32.into_ref();
+    assert_eq_hex!(r.val(), 0);
+
+    let mut buf = [0xA];
+    let (b, _): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    assert_eq!(b.downgrade().val(), 0xA);
+
+    let (a, _): (ArrayView<[u8; 1]>, _) = ArrayView::mut_view(&mut buf);
+    assert_eq_hex!(*a.downgrade().as_ref(), [0xA]);
+    assert_eq_hex!(*a.into_ref().as_ref(), [0xA]);
+}
+
+crate::impl_ref_view! {
+    struct RefViewTest<'a> {
+        pub a: ByteRef<'a, u8>,
+        pub b: MulByteRef<'a, u16, BigEnd>,
+        pub c: Option<u8>,
+        pub d: ArrayRef<'a, [u8; 2]>,
+    }
+}
+
+#[test]
+fn ref_view_struct() {
+    let buf = [0x1, 0x2, 0x3, 0x4, 0x5, 0x6];
+
+    let (t, leftover) = RefViewTest::ref_view(&buf);
+    assert_eq!(t.a.val(), 0x1);
+    assert_eq_hex!(t.b.val(), 0x0203);
+    assert_eq!(t.c, None);
+    assert_eq_hex!(*t.d.as_ref(), [0x4, 0x5]);
+    assert_eq!(leftover, [0x6]);
+
+    let (t, _) = RefViewTest::try_ref_view(&buf).unwrap();
+    assert_eq_hex!(t.b.val(), 0x0203);
+
+    let err = RefViewTest::try_ref_view(&buf[..4]).err().unwrap();
+    assert_eq!(
+        err,
+        crate::Error::OutOfBounds {
+            needed: 2,
+            available: 1,
+            offset: 3
+        }
+    );
+}
+
+#[derive(MutView)]
+struct FloatTest<'a> {
+    pub lit_32: MulByteView<'a, f32, LitEnd>,
+    pub big_32: MulByteView<'a, f32, BigEnd>,
+    pub lit_64: MulByteView<'a, f64, LitEnd>,
+    pub big_64: MulByteView<'a, f64, BigEnd>,
+}
+
+const F32_RESULT: f32 = 1.5;
+const F64_RESULT: f64 = -1234.0625;
+
+#[test]
+fn mulbyteval_float() {
+    let mut buf = vec![0; 24];
+    let (mut t, _) = FloatTest::mut_view(&mut buf);
+
+    t.lit_32.set(F32_RESULT);
+    t.big_32.set(F32_RESULT);
+    t.lit_64.set(F64_RESULT);
+    t.big_64.set(F64_RESULT);
+
+    assert_eq!(t.lit_32.val(), F32_RESULT);
+    assert_eq!(t.big_32.val(), F32_RESULT);
+    assert_eq!(t.lit_64.val(), F64_RESULT);
+    assert_eq!(t.big_64.val(), F64_RESULT);
+
+    assert_eq_hex!(buf[0..4], F32_RESULT.to_le_bytes());
+    assert_eq_hex!(buf[4..8], F32_RESULT.to_be_bytes());
+    assert_eq_hex!(buf[8..16], F64_RESULT.to_le_bytes());
+    assert_eq_hex!(buf[16..24], F64_RESULT.to_be_bytes());
+}
+
+#[test]
+fn mulbyteval_float_assign() {
+    let mut buf = vec![0; 24];
+    let (mut t, _) = FloatTest::mut_view(&mut buf);
+
+    t.lit_32.set(F32_RESULT);
+    t.big_64.set(F64_RESULT);
+
+    t.lit_32 += 0.5;
+    t.lit_32 *= 4.0;
+    t.lit_32 -= 1.0;
+    t.lit_32 /= 2.0;
+    assert_eq!(t.lit_32.val(), 3.5);
+
+    t.big_64 /= 2.0;
+    assert_eq!(t.big_64.val(), F64_RESULT / 2.0);
+    assert_eq!(t.big_64.downgrade().val(), F64_RESULT / 2.0);
+}
+
+crate::impl_set_endian! {
+    #[derive(MutView)]
+    struct DynEndTest<'a> {
+        pub unsigned_16: MulByteView<'a, u16, Endian>,
+        pub signed_32: MulByteView<'a, i32, Endian>,
+        pub float_64: MulByteView<'a, f64, Endian>,
+    }
+}
+
+#[test]
+fn mulbyteval_dynend() {
+    let mut buf = vec![0; 14];
+    let (mut t, _) = DynEndTest::mut_view(&mut buf);
+
+    assert_eq!(t.unsigned_16.endian(), Endian::Little);
+    t.unsigned_16.set(U16_BE_RESULT);
+    t.signed_32.set(I32_BE_RESULT);
+
+    t.set_endian(Endian::Big);
+    assert_eq_hex!(t.unsigned_16.val(), U16_BE_RESULT.swap_bytes());
+    assert_eq_hex!(t.signed_32.val(), I32_BE_RESULT.swap_bytes());
+
+    t.float_64.set(F64_RESULT);
+    t.float_64 += 1.0;
+    assert_eq!(t.float_64.downgrade().val(), F64_RESULT + 1.0);
+
+    assert_eq_hex!(buf[0..2], U16_BE_RESULT.to_le_bytes());
+    assert_eq_hex!(buf[2..6], I32_BE_RESULT.to_le_bytes());
+    assert_eq_hex!(buf[6..14], (F64_RESULT + 1.0).to_be_bytes());
+}
+
+crate::impl_set_endian! {
+    #[derive(MutView)]
+    struct DynEndNestedTest<'a> {
+        pub kind: ByteView<'a, u8>,
+        pub fixed: MulByteView<'a, u16, LitEnd>,
+        pub inner: DynEndTest<'a>,
+        pub arr: ArrayView<'a, [u16; 1], Endian>,
+        pub name: Utf16View<'a, Endian, 1>,
+        pub extra: Option<u8>,
+    }
+}
+
+#[test]
+fn set_endian_every_field() {
+    let mut buf = vec![0; 21];
+    let (mut t, _) = DynEndNestedTest::mut_view(&mut buf);
+
+    t.set_endian(Endian::Big);
+    assert_eq!(t.inner.unsigned_16.endian(), Endian::Big);
+    assert_eq!(t.inner.signed_32.endian(), Endian::Big);
+    assert_eq!(t.inner.float_64.endian(), Endian::Big);
+    assert_eq!(t.arr.endian(), Endian::Big);
+    assert_eq!(t.name.endian(), Endian::Big);
+
+    t.fixed.set(0x0102);
+    assert_eq_hex!(buf[1..3], [0x02, 0x01]);
+}
+
+#[test]
+fn mulbyteval_dynend_view_endian() {
+    let buf = U32_BE_RESULT.to_be_bytes();
+
+    let (r, _): (MulByteRef<u32, Endian>, _) = MulByteRef::ref_view_endian(&buf, Endian::Big);
+    assert_eq_hex!(r.val(), U32_BE_RESULT);
+
+    let mut buf = buf;
+    let (v, _): (MulByteView<u32, Endian>, _) =
+        MulByteView::try_mut_view_endian(&mut buf, Endian::Little).unwrap();
+    assert_eq_hex!(v.val(), U32_BE_RESULT.swap_bytes());
+}
+
+#[derive(MutView)]
+struct NativeEndTest<'a> {
+    pub unsigned_16: MulByteView<'a, u16, NativeEnd>,
+    pub signed_64: MulByteView<'a, i64, NativeEnd>,
+    pub network_32: MulByteView<'a, u32, NetworkEnd>,
+}
+
+#[test]
+fn mulbyteval_nativeend() {
+    let mut buf = vec![0; 14];
+    let (mut t, _) = NativeEndTest::mut_view(&mut buf);
+
+    t.unsigned_16.set(U16_BE_RESULT);
+    t.signed_64.set(I64_BE_RESULT);
+    t.network_32.set(U32_BE_RESULT);
+
+    assert_eq_hex!(t.unsigned_16.val(), U16_BE_RESULT);
+    assert_eq_hex!(t.signed_64.downgrade().val(), I64_BE_RESULT);
+
+    assert_eq_hex!(buf[0..2], U16_BE_RESULT.to_ne_bytes());
+    assert_eq_hex!(buf[2..10], I64_BE_RESULT.to_ne_bytes());
+    assert_eq_hex!(buf[10..14], U32_BE_RESULT.to_be_bytes());
+}
+
+#[derive(MutView)]
+struct IntViewTest<'a> {
+    pub unsigned_24: U24View<'a, BigEnd>,
+    pub signed_24: I24View<'a, LitEnd>,
+    pub unsigned_48: U48View<'a, LitEnd>,
+    pub signed_56: I56View<'a, Endian>,
+}
+
+#[test]
+fn intview_val() {
+    let mut buf = vec![
+        0x01, 0x02, 0x03, 0xFE, 0xFF, 0xFF, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0x80,
+    ];
+    let (mut t, leftover) = IntViewTest::mut_view(&mut buf);
+
+    assert!(leftover.is_empty());
+    assert_eq_hex!(t.unsigned_24.val(), 0x010203);
+    assert_eq!(t.signed_24.val(), -2);
+    assert_eq_hex!(t.unsigned_48.val(), 0x060504030201);
+    assert_eq!(t.signed_56.val(), -0x7F_0000_0000_0001);
+
+    t.signed_56.set_endian(Endian::Big);
+    assert_eq!(t.signed_56.val(), -0x80);
+}
+
+#[test]
+fn intview_set() {
+    let mut buf = vec![0; 19];
+    let (mut t, _) = IntViewTest::mut_view(&mut buf);
+
+    t.unsigned_24.set(0xABCDEF).unwrap();
+    t.signed_24.set(-0x800000).unwrap();
+    t.unsigned_48.set(0xFFFF_FFFF_FFFF).unwrap();
+
+    assert_eq!(
+        t.unsigned_24.set(0x1000000),
+        Err(crate::Error::Overflow { size: 3, offset: 0 })
+    );
+    assert!(t.signed_24.set(-0x800001).is_err());
+    assert!(t.signed_24.set(0x800000).is_err());
+    assert!(t.unsigned_48.set(0x1_0000_0000_0000).is_err());
+
+    assert_eq_hex!(buf[0..3], [0xAB, 0xCD, 0xEF]);
+    assert_eq_hex!(buf[3..6], [0x00, 0x00, 0x80]);
+    assert_eq_hex!(buf[6..12], [0xFF; 6]);
+}
+
+const VERSION: Bits<u8> = Bits::new(0, 4);
+const IHL: Bits<u8> = Bits::new(4, 8);
+const FRAG_FLAGS: Bits<u8> = Bits::new(0, 3);
+const FRAG_OFFSET: Bits<u16> = Bits::new(3, 16);
+
+#[derive(MutView)]
+struct BitfieldTest<'a> {
+    pub version_ihl: BitfieldView<'a, u8, BigEnd, Msb0>,
+    pub frag: BitfieldView<'a, u16, BigEnd, Msb0>,
+    pub lsb: BitfieldView<'a, u32, LitEnd>,
+}
+
+#[test]
+fn bitfield_val() {
+    let mut buf = vec![0x45, 0x40, 0x01, 0xF0, 0x0F, 0x00, 0x00];
+    let (t, _) = BitfieldTest::mut_view(&mut buf);
+
+    assert_eq!(t.version_ihl.val(VERSION), 4);
+    assert_eq!(t.version_ihl.val(IHL), 5);
+    assert_eq!(t.frag.val(FRAG_FLAGS), 0b010);
+    assert_eq_hex!(t.frag.val(FRAG_OFFSET), 0x0001);
+    assert_eq_hex!(t.lsb.val(Bits::<u8>::new(4, 12)), 0xFF);
+    assert_eq_hex!(t.lsb.raw(), 0x0F_F0);
+}
+
+#[test]
+fn bitfield_set() {
+    let mut buf = vec![0xFF; 7];
+    let (mut t, _) = BitfieldTest::mut_view(&mut buf);
+
+    t.version_ihl.set(IHL, 0).unwrap();
+    t.frag.set(FRAG_FLAGS, 0).unwrap();
+    t.frag.set(FRAG_OFFSET, 0x0100).unwrap();
+    t.lsb.set(Bits::<u16>::new(4, 16), 0).unwrap();
+
+    assert_eq!(
+        t.version_ihl.set(VERSION, 0x10),
+        Err(crate::Error::Overflow { size: 1, offset: 0 })
+    );
+    assert!(t.frag.set(FRAG_OFFSET, 0x2000).is_err());
+
+    assert_eq_hex!(buf, [0xF0, 0x01, 0x00, 0x0F, 0x00, 0xFF, 0xFF]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EtherType {
+    Ipv4 = 0x0800,
+    Arp = 0x0806,
+}
+
+impl std::convert::TryFrom<u16> for EtherType {
+    type Error = ();
+
+    fn try_from(v: u16) -> Result<Self, Self::Error> {
+        match v {
+            0x0800 => Ok(EtherType::Ipv4),
+            0x0806 => Ok(EtherType::Arp),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<EtherType> for u16 {
+    fn from(v: EtherType) -> Self {
+        v as u16
+    }
+}
+
+#[derive(MutView)]
+struct EnumViewTest<'a> {
+    pub ether_type: EnumView<'a, EtherType, u16, BigEnd>,
+    pub dyn_ether_type: EnumView<'a, EtherType, u16, Endian>,
+}
+
+#[test]
+fn enumview_val() {
+    let mut buf = vec![0x08, 0x06, 0x00, 0x08];
+    let (mut t, _) = EnumViewTest::mut_view(&mut buf);
+
+    assert_eq!(t.ether_type.val(), Ok(EtherType::Arp));
+    assert_eq!(t.dyn_ether_type.val(), Ok(EtherType::Ipv4));
+
+    t.dyn_ether_type.set_endian(Endian::Big);
+    assert_eq!(t.dyn_ether_type.val(), Err(Unknown(0x0008)));
+    assert_eq_hex!(t.dyn_ether_type.raw(), 0x0008);
+}
+
+#[test]
+fn enumview_set() {
+    let mut buf = vec![0; 4];
+    let (mut t, _) = EnumViewTest::mut_view(&mut buf);
+
+    t.ether_type.set(EtherType::Ipv4);
+    t.dyn_ether_type.set_raw(0x86DD);
+
+    assert_eq!(t.dyn_ether_type.val(), Err(Unknown(0x86DD)));
+    assert_eq_hex!(buf, [0x08, 0x00, 0xDD, 0x86]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TcpFlags(u8);
+
+impl TcpFlags {
+    const FIN: TcpFlags = TcpFlags(0x01);
+    const SYN: TcpFlags = TcpFlags(0x02);
+    const ACK: TcpFlags = TcpFlags(0x10);
+}
+
+impl From<u8> for TcpFlags {
+    fn from(v: u8) -> Self {
+        TcpFlags(v)
+    }
+}
+
+impl From<TcpFlags> for u8 {
+    fn from(v: TcpFlags) -> Self {
+        v.0
+    }
+}
+
+#[derive(MutView)]
+struct FlagsViewTest<'a> {
+    pub tcp: FlagsView<'a, u8, BigEnd, TcpFlags>,
+    pub raw: FlagsView<'a, u32, LitEnd>,
+}
+
+#[test]
+fn flagsview_ops() {
+    let mut buf = vec![0x12, 0x01, 0x00, 0x00, 0x80];
+    let (mut t, _) = FlagsViewTest::mut_view(&mut buf);
+
+    assert!(t.tcp.contains(TcpFlags::SYN));
+    assert!(t.tcp.contains(TcpFlags(0x12)));
+    assert!(!t.tcp.contains(TcpFlags::FIN));
+    assert_eq!(
+        t.tcp.iter().collect::<Vec<_>>(),
+        vec![TcpFlags::SYN, TcpFlags::ACK]
+    );
+
+    t.tcp.remove(TcpFlags::SYN);
+    t.tcp.insert(TcpFlags::FIN);
+    t.tcp.toggle(TcpFlags::ACK);
+    assert_eq!(t.tcp.val(), TcpFlags::FIN);
+
+    assert_eq!(t.raw.iter().collect::<Vec<_>>(), vec![0x1, 0x8000_0000]);
+    t.raw |= 0x0100;
+    t.raw &= !0x8000_0000;
+    t.raw ^= 0x3;
+    assert_eq_hex!(t.raw.raw(), 0x0102);
+
+    assert_eq_hex!(buf, [0x01, 0x02, 0x01, 0x00, 0x00]);
+}
+
+#[test]
+fn byteval_assign() {
+    let mut buf = vec![0xF0];
+    let (mut b, _): (ByteView<u8>, &mut [u8]) = ByteView::mut_view(&mut buf);
+
+    b &= 0x3C;
+    b |= 0x01;
+    b ^= 0x11;
+    b <<= 1;
+    b >>= 2;
+    assert_eq_hex!(b.val(), 0x10);
+
+    b %= 5;
+    assert_eq_hex!(buf[0], 0x01);
+
+    let mut buf = vec![0x04, 0x00];
+    let (mut m, _): (MulByteView<u16, BigEnd>, _) = MulByteView::mut_view(&mut buf);
+    m <<= 4;
+    assert_eq_hex!(buf, [0x40, 0x00]);
+}
+
+#[test]
+fn checked_arith() {
+    let mut buf = vec![0xFE, 0x00, 0xFF];
+    let (mut b, buf_rem): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    let (mut m, _): (MulByteView<u16, LitEnd>, _) = MulByteView::mut_view(buf_rem);
+
+    assert_eq!(b.checked_add(1), Some(0xFF));
+    assert_eq!(b.checked_add(1), None);
+    assert_eq!(b.val(), 0xFF);
+    assert_eq!(b.wrapping_add(2), 0x01);
+    assert_eq!(b.saturating_sub(2), 0x00);
+    assert_eq!(b.checked_sub(1), None);
+
+    assert_eq!(m.checked_mul(2), None);
+    assert_eq_hex!(m.val(), 0xFF00);
+    assert_eq_hex!(m.saturating_mul(2), 0xFFFF);
+    assert_eq_hex!(m.wrapping_mul(2), 0xFFFE);
+    assert_eq_hex!(m.checked_sub(0xFFFE), Some(0));
+
+    assert_eq_hex!(buf, [0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn checked_arith_signed() {
+    let mut buf = vec![0x7F, 0xFF, 0xFF, 0xFF];
+    let (mut m, _): (MulByteView<i32, BigEnd>, _) = MulByteView::mut_view(&mut buf);
+
+    assert_eq!(m.checked_add(1), None);
+    assert_eq!(m.saturating_add(1), i32::MAX);
+    assert_eq!(m.wrapping_add(1), i32::MIN);
+    assert_eq!(m.checked_sub(1), None);
+    assert_eq_hex!(buf, [0x80, 0x00, 0x00, 0x00]);
+}
+
+#[derive(MutView)]
+struct TypedArrayTest<'a> {
+    pub rvas: ArrayView<'a, [u32; 3], LitEnd>,
+    pub palette: ArrayView<'a, [u16; 2], BigEnd>,
+    pub dyn_arr: ArrayView<'a, [i16; 2], Endian>,
+}
+
+#[test]
+fn typed_arrayval_get_set() {
+    let mut buf = vec![
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0xAB, 0xCD, 0x00,
+        0x01, 0xFF, 0xFF, 0x00, 0x80,
+    ];
+    let (mut t, _) = TypedArrayTest::mut_view(&mut buf);
+
+    assert_eq!(t.rvas.len(), 3);
+    assert_eq!(t.rvas.get(2), Some(3));
+    assert_eq!(t.rvas.get(3), None);
+    assert_eq_hex!(t.palette.get(0), Some(0xABCD));
+    assert_eq!(t.dyn_arr.iter().collect::<Vec<_>>(), vec![-1, -0x8000]);
+
+    t.dyn_arr.set_endian(Endian::Big);
+    assert_eq!(t.dyn_arr.get(1), Some(0x80));
+
+    t.rvas.set(1, 0x11223344);
+    t.palette.set(1, 0x1234);
+
+    assert_eq_hex!(buf[4..8], [0x44, 0x33, 0x22, 0x11]);
+    assert_eq_hex!(buf[14..16], [0x12, 0x34]);
+}
+
+#[test]
+fn typed_arrayval_copy() {
+    let mut buf = vec![0; 20];
+    let (mut t, _) = TypedArrayTest::mut_view(&mut buf);
+
+    t.rvas.copy_from(&[0x1, 0x2, 0x3]);
+    t.palette.copy_from(&[0xAABB, 0xCCDD]);
+
+    let mut rvas = [0; 3];
+    t.rvas.copy_to(&mut rvas);
+    assert_eq!(rvas, [0x1, 0x2, 0x3]);
+
+    assert_eq_hex!(buf[12..16], [0xAA, 0xBB, 0xCC, 0xDD]);
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn views_send_sync() {
+    assert_send_sync::<ByteView<u8>>();
+    assert_send_sync::<MulByteView<u32, LitEnd>>();
+    assert_send_sync::<MulByteView<u32, Endian>>();
+    assert_send_sync::<ArrayView<[u8; 4]>>();
+    assert_send_sync::<ArrayView<[u16; 4], BigEnd>>();
+    assert_send_sync::<ByteRef<u8>>();
+    assert_send_sync::<MulByteRef<u64, NativeEnd>>();
+    assert_send_sync::<ArrayRef<[u8; 4]>>();
+    assert_send_sync::<U24View<BigEnd>>();
+    assert_send_sync::<BitfieldView<u16, BigEnd, Msb0>>();
+    assert_send_sync::<EnumView<EtherType, u16, BigEnd>>();
+    assert_send_sync::<SeqView<ByteView<u8>>>();
+    assert_send_sync::<FlagsView<u8, BigEnd, TcpFlags>>();
+    assert_send_sync::<BigEndUnsignTest>();
+}
+
+#[test]
+fn split_views_threads() {
+    let mut buf = vec![0; 30 * 4 + 3];
+    let (records, leftover) = crate::split_views(&mut buf, 30, BigEndUnsignTest::mut_view);
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(leftover.len(), 3);
+
+    std::thread::scope(|s| {
+        for (i, mut r) in records.into_iter().enumerate() {
+            s.spawn(move || r.unsigned_16.set(i as u16 + 1));
+        }
+    });
+
+    assert_eq_hex!(buf[0..2], [0x00, 0x01]);
+    assert_eq_hex!(buf[90..92], [0x00, 0x04]);
+}
+
+#[test]
+fn try_split_views_err() {
+    let mut buf = vec![0, 0, 0, 0, 0xFF, 0, 0, 0];
+    let r = crate::try_split_views(&mut buf, 4, |b| {
+        if b[0] == 0xFF {
+            MulByteView::<u64, LitEnd>::try_mut_view(b).map(|(_, l)| ((), l))
+        } else {
+            Ok(((), b))
+        }
+    });
+
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 8,
+            available: 4,
+            offset: 4
+        })
+    );
+
+    let mut buf = vec![0; 8];
+    let (records, _) =
+        crate::try_split_views(&mut buf, 4, MulByteView::<u32, LitEnd>::try_mut_view).unwrap();
+    assert_eq!(records.len(), 2);
+}
+
+#[test]
+fn sliceview_len() {
+    let mut buf = vec![0x1, 0x2, 0x3, 0x4];
+    let (mut s, leftover) = SliceView::mut_view(&mut buf, 3);
+
+    assert_eq!(s.len(), 3);
+    assert_eq!(leftover.len(), 1);
+    s.as_mut().copy_from_slice(&[0xA, 0xB, 0xC]);
+    assert_eq_hex!(*s.as_ref(), [0xA, 0xB, 0xC]);
+
+    let r = SliceView::try_mut_view(leftover, 2);
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 2,
+            available: 1,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn sliceview_from_field() {
+    let mut buf = vec![0x02, 0x00, 0x00, 0x00, 0x02, 0xAA, 0xBB, 0xCC];
+    let (b, rest): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    let (m, rest): (MulByteView<u32, BigEnd>, _) = MulByteView::mut_view(rest);
+    let (s, rest) = SliceView::mut_view_from(rest, &b);
+
+    assert_eq_hex!(*s.as_ref(), [0xAA, 0xBB]);
+    assert!(SliceView::try_mut_view_from(rest, &m).is_err());
+
+    let (i, _): (MulByteRef<i16, BigEnd>, _) = MulByteRef::ref_view(&[0xFF, 0xFF]);
+    assert_eq!(i.length(), usize::MAX);
+
+    let mut buf = vec![0x00, 0x00, 0x02, 0xAA, 0xBB, 0xCC];
+    let (n, rest): (U24View<BigEnd>, _) = IntView::mut_view(&mut buf);
+    let (s, _) = SliceView::mut_view_from(rest, &n);
+    assert_eq_hex!(*s.as_ref(), [0xAA, 0xBB]);
+
+    let mut buf = vec![0xFF, 0xFF, 0xFF];
+    let (n, _): (I24View<LitEnd>, _) = IntView::mut_view(&mut buf);
+    assert_eq!(n.length(), usize::MAX);
+}
+
+#[test]
+fn seqview_records() {
+    let mut buf = vec![0; 30 * 3 + 1];
+    let (mut seq, leftover) = SeqView::mut_view(&mut buf, 3, 30, BigEndUnsignTest::mut_view);
+
+    assert_eq!(seq.len(), 3);
+    assert_eq!(leftover.len(), 1);
+    assert!(seq.get(3).is_none());
+    assert!(seq.get_mut(3).is_none());
+    assert_eq!(seq.get(0).unwrap().unsigned_16.val(), 0);
+
+    seq.get_mut(2).unwrap().unsigned_16.set(0xAABB);
+    for (i, r) in seq.iter_mut().enumerate() {
+        r.unsigned_32 += i as u32;
+    }
+    assert_eq_hex!(seq.get_mut(2).unwrap().unsigned_16.val(), 0xAABB);
+
+    let records = seq.into_vec();
+    assert_eq_hex!(records[1].unsigned_32.val(), 1);
+
+    assert_eq_hex!(buf[60..66], [0xAA, 0xBB, 0x00, 0x00, 0x00, 0x02]);
+}
+
+#[test]
+fn seqview_zero_stride() {
+    let mut buf = vec![0x01, 0x02];
+
+    let r = SeqView::try_mut_view(&mut buf, 1 << 40, 0, ByteView::<u8>::mut_view);
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::Invalid {
+            reason: "record stride is zero",
+            offset: 0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "record stride is zero")]
+fn seqview_zero_stride_panic() {
+    let mut buf = vec![0x01, 0x02];
+
+    SeqView::mut_view(&mut buf, 1 << 40, 0, ByteView::<u8>::mut_view);
+}
+
+#[test]
+#[should_panic(expected = "record count times stride overflows usize")]
+fn seqview_stride_overflow() {
+    let mut buf = vec![0x01, 0x02];
+
+    SeqView::mut_view(&mut buf, usize::MAX, 2, ByteView::<u8>::mut_view);
+}
+
+#[test]
+fn seqview_from_field() {
+    let mut buf = vec![0x03, 0x01, 0x02];
+    let (count, rest): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+
+    let r = SeqView::try_mut_view_from(rest, &count, 1, ByteView::<u8>::mut_view);
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 3,
+            available: 2,
+            offset: 0
+        })
+    );
+
+    let mut buf = vec![0x02, 0x01, 0x02];
+    let (count, rest): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    let (seq, _) = SeqView::mut_view_from(rest, &count, 1, ByteView::<u8>::mut_view);
+
+    assert_eq!(
+        seq.into_vec().iter().map(|b| b.val()).collect::<Vec<_>>(),
+        vec![0x01, 0x02]
+    );
+
+    let mut buf = vec![0x02, 0x00, 0x00, 0x01, 0x02, 0x03];
+    let (count, rest): (U24View<LitEnd>, _) = IntView::mut_view(&mut buf);
+    let (seq, leftover) = SeqView::mut_view_from(rest, &count, 1, ByteView::<u8>::mut_view);
+
+    assert_eq!(seq.len(), 2);
+    assert_eq!(leftover.len(), 1);
+}
+
+crate::impl_view_size! {
+    #[derive(MutView)]
+    struct ViewSizeInner<'a> {
+        pub u8_f: ByteView<'a, u8>,
+        pub u24_f: U24View<'a, BigEnd>,
+    }
+}
+
+crate::impl_view_size! {
+    #[derive(MutView)]
+    struct ViewSizeTest<'a> {
+        pub inner: ViewSizeInner<'a>,
+        pub arr_f: ArrayView<'a, [u16; 3], LitEnd>,
+        pub flags: FlagsView<'a, u32, BigEnd>,
+        pub option_f: Option<u8>,
+    }
+}
+
+#[test]
+fn view_size() {
+    assert_eq!(<ByteView<i8>>::SIZE, 1);
+    assert_eq!(<MulByteView<u64, LitEnd>>::SIZE, 8);
+    assert_eq!(<ArrayView<[u8; 5]>>::SIZE, 5);
+    assert_eq!(<MulByteRef<f32, BigEnd>>::SIZE, 4);
+    assert_eq!(<EnumView<EtherType, u16, BigEnd>>::SIZE, 2);
+    assert_eq!(<BitfieldView<u16, BigEnd, Msb0>>::SIZE, 2);
+    assert_eq!(ViewSizeInner::SIZE, 4);
+    assert_eq!(ViewSizeTest::SIZE, 14);
+
+    let mut buf = vec![0; ViewSizeTest::SIZE + 1];
+    let (t, leftover) = ViewSizeTest::mut_view(&mut buf);
+    assert_eq!(leftover.len(), 1);
+    assert_eq!(t.option_f, None);
+}
+
+struct VersionView<'a>(ByteView<'a, u8>);
+
+impl<'a> ViewSize for VersionView<'a> {
+    const SIZE: usize = 1;
+}
+
+impl<'a> ZordonView<'a> for VersionView<'a> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (v, arr) = ByteView::mut_view(arr);
+        (VersionView(v), arr)
+    }
+
+    fn validate(&self) -> crate::Result<()> {
+        match self.0.val() {
+            4 | 6 => Ok(()),
+            _ => Err(crate::Error::Invalid {
+                reason: "unknown version",
+                offset: 0,
+            }),
+        }
+    }
+}
+
+crate::impl_try_mut_view! {
+    #[derive(MutView)]
+    struct ZordonViewTest<'a> {
+        pub len: MulByteView<'a, u16, BigEnd>,
+        pub version: VersionView<'a>,
+    }
+}
+
+#[test]
+fn zordon_view_custom() {
+    let mut buf = vec![0x00, 0x10, 0x06];
+    let (t, _) = ZordonViewTest::mut_view(&mut buf);
+    assert_eq!(t.len.val(), 0x10);
+    assert_eq!(t.version.0.val(), 6);
+
+    let mut buf = vec![0x00, 0x10, 0x06];
+    assert!(ZordonViewTest::try_mut_view(&mut buf).is_ok());
+
+    let mut buf = vec![0x00, 0x10, 0x05];
+    assert_eq!(
+        ZordonViewTest::try_mut_view(&mut buf).err(),
+        Some(crate::Error::Invalid {
+            reason: "unknown version",
+            offset: 2
+        })
+    );
+}
+
+#[test]
+fn zordon_view_generic() {
+    fn parse<'a, V: ZordonView<'a>>(arr: &'a mut [u8]) -> crate::Result<V> {
+        V::try_mut_view(arr).map(|(v, _)| v)
+    }
+
+    let mut buf = vec![0x12, 0x34];
+    let v: MulByteView<u16, BigEnd> = parse(&mut buf).unwrap();
+    assert_eq!(v.val(), 0x1234);
+
+    let mut buf = vec![0x12];
+    assert_eq!(
+        parse::<EnumView<EtherType, u16, BigEnd>>(&mut buf).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 2,
+            available: 1,
+            offset: 0
+        })
+    );
+}
+
+crate::impl_view_offset! {
+    #[derive(MutView)]
+    struct ViewOffsetTest<'a> {
+        pub kind: ByteView<'a, u8>,
+        pub len: MulByteView<'a, u16, BigEnd>,
+        pub id: U24View<'a, LitEnd>,
+        pub option_f: Option<u8>,
+    }
+}
+
+#[test]
+fn view_offset() {
+    let mut buf = vec![0xFF, 0x01, 0x00, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x07, 0x08, 0x09];
+    let root = Root::new(&buf);
+    let (magic, rest): (ArrayView<[u8; 2]>, _) = ArrayView::mut_view(&mut buf);
+    let (t, rest) = ViewOffsetTest::mut_view(rest);
+    let (records, _) = SeqView::mut_view(rest, 2, 2, ByteView::<u8>::mut_view);
+
+    assert_eq!(magic.range(root), 0..2);
+    assert_eq!(t.kind.offset(root), 2);
+    assert_eq!(t.len.range(root), 3..5);
+    assert_eq!(t.id.range(root), 5..8);
+    assert_eq!(t.range(root), 2..8);
+    assert_eq!(records.range(root), 8..12);
+
+    let mut buf = vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+    let root = Root::new(&buf);
+    let (_, rest) = buf.split_at_mut(2);
+    let (mut seq, _) = SeqView::mut_view(rest, 2, 2, MulByteView::<u16, LitEnd>::mut_view);
+    assert_eq!(seq.range(root), 2..6);
+    assert_eq!(seq.get_mut(1).unwrap().offset(root), 4);
+}
+
+#[test]
+#[should_panic(expected = "view is not within the root buffer")]
+fn view_offset_other_root() {
+    let other = vec![0x00; 4];
+    let mut buf = vec![0x00; 4];
+    let (v, _): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+
+    v.offset(Root::new(&other));
+}
+
+crate::impl_try_mut_view! {
+    impl_view_size! {
+        #[derive(MutView)]
+        struct PadViewTest<'a> {
+            pub kind: ByteView<'a, u8>,
+            pub _pad: PadView<'a, 3>,
+            pub reserved: ReservedView<'a, [u8; 4]>,
+            pub len: MulByteView<'a, u16, BigEnd>,
+        }
+    }
+}
+
+#[test]
+fn pad_view() {
+    assert_eq!(PadViewTest::SIZE, 10);
+
+    let mut buf = vec![0x01, 0xAA, 0xBB, 0xCC, 0x00, 0x00, 0x10, 0x00, 0x00, 0x20];
+    let (mut t, _) = PadViewTest::try_mut_view(&mut buf).unwrap();
+    assert_eq!(t.kind.val(), 0x01);
+    assert_eq!(t.len.val(), 0x20);
+    assert!(!t.reserved.is_zero());
+    assert_eq!(
+        t.reserved.verify(),
+        Err(crate::Error::Invalid {
+            reason: "reserved bytes are not zero",
+            offset: 2
+        })
+    );
+
+    t.reserved.clear();
+    assert!(t.reserved.verify().is_ok());
+    assert_eq!(buf, [0x01, 0xAA, 0xBB, 0xCC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20]);
+
+    let mut buf = vec![0x01, 0xAA, 0xBB];
+    assert_eq!(
+        PadViewTest::try_mut_view(&mut buf).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 3,
+            available: 2,
+            offset: 1
+        })
+    );
+}
+
+#[test]
+fn align_view() {
+    assert_eq!(AlignView::<4>::padding(0), 0);
+    assert_eq!(AlignView::<4>::padding(5), 3);
+    assert_eq!(AlignView::<8>::padding(16), 0);
+
+    let mut buf = vec![0x01, 0xAA, 0xBB, 0xCC, 0x02];
+    let (kind, rest): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    let (pad, rest) = AlignView::<4>::mut_view_at(rest, ByteView::<u8>::SIZE);
+    let (id, _): (ByteView<u8>, _) = ByteView::mut_view(rest);
+    assert_eq!(kind.val(), 0x01);
+    assert_eq!(pad.len(), 3);
+    assert_eq!(id.val(), 0x02);
+
+    let mut buf = vec![0x01, 0xAA];
+    assert_eq!(
+        AlignView::<4>::try_mut_view_at(&mut buf, 1).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 3,
+            available: 2,
+            offset: 0
+        })
+    );
+}
+
+crate::impl_magic!(struct ElfMagic = b"\x7fELF");
+crate::impl_magic!(struct PeMagic = 0x0000_4550u32, LitEnd);
+
+crate::impl_try_mut_view! {
+    #[derive(MutView)]
+    struct MagicViewTest<'a> {
+        pub dos: ArrayView<'a, [u8; 2]>,
+        pub pe: MagicView<'a, PeMagic>,
+        pub machine: MulByteView<'a, u16, LitEnd>,
+    }
+}
+
+#[test]
+fn magic_view() {
+    let mut buf = vec![0x00, 0x00, b'P', b'E', 0x00, 0x00, 0x64, 0x86];
+    let (t, _) = MagicViewTest::try_mut_view(&mut buf).unwrap();
+    assert!(t.pe.is_valid());
+    assert_eq!(t.pe.as_ref(), b"PE\0\0");
+    assert_eq!(t.machine.val(), 0x8664);
+
+    let mut buf = vec![0x00, 0x00, b'P', b'F', 0x00, 0x00, 0x64, 0x86];
+    let err = MagicViewTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!(
+        err,
+        crate::Error::BadMagic {
+            expected: b"PE\0\0",
+            found: vec![b'P', b'F', 0x00, 0x00],
+            offset: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "bad magic at offset 0x2: expected [50, 45, 00, 00] but found [50, 46, 00, 00]"
+    );
+
+    let (mut t, _) = MagicViewTest::mut_view(&mut buf);
+    assert!(t.pe.verify().is_err());
+    t.pe.restore();
+    assert!(t.pe.verify().is_ok());
+    assert_eq!(buf, [0x00, 0x00, b'P', b'E', 0x00, 0x00, 0x64, 0x86]);
+}
+
+#[test]
+fn magic_view_short() {
+    assert_eq!(<MagicView<ElfMagic>>::SIZE, 4);
+
+    let mut buf = vec![0x7F, b'E', b'L'];
+    assert_eq!(
+        MagicView::<ElfMagic>::try_mut_view(&mut buf).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 4,
+            available: 3,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn seqview_try_records_validate() {
+    let mut buf = b"PE\0\0PF\0\0PE\0\0".to_vec();
+    let r = SeqView::<MagicView<PeMagic>>::try_records(&mut buf, 3);
+    assert_eq!(
+        r.err(),
+        Some(crate::Error::BadMagic {
+            expected: b"PE\0\0",
+            found: b"PF\0\0".to_vec(),
+            offset: 4
+        })
+    );
+
+    buf[5] = b'E';
+    let (seq, leftover) = SeqView::<MagicView<PeMagic>>::try_records(&mut buf, 3).unwrap();
+    assert!(seq.get(1).unwrap().is_valid());
+    assert!(leftover.is_empty());
+}
+
+#[derive(MutView)]
+struct TextViewTest<'a> {
+    pub name: AsciiView<'a, 8>,
+    pub title: Utf8View<'a, 6>,
+    pub le: Utf16View<'a, LitEnd, 3>,
+    pub be: Utf16View<'a, BigEnd, 2>,
+}
+
+#[test]
+fn text_view_val() {
+    let mut buf = b".text\0\0\0caf\xC3\xA9\0A\0B\0\0\0\xD8\x3D\xDE\x00".to_vec();
+    let (t, _) = TextViewTest::mut_view(&mut buf);
+    assert_eq!(t.name.val().unwrap(), ".text");
+    assert_eq!(t.name.as_bytes(), b".text\0\0\0");
+    assert_eq!(t.title.val().unwrap(), "café");
+    assert_eq!(t.le.val().unwrap(), "AB");
+    assert_eq!(t.be.val().unwrap(), "\u{1F600}");
+
+    let mut buf = b"fu\xFFll\0\0\0caf\xC3\0\0\0\xDC\0\0\0\0\xD8\x3D\0\0".to_vec();
+    let (t, _) = TextViewTest::mut_view(&mut buf);
+    let invalid = |reason, offset| Some(crate::Error::Invalid { reason, offset });
+    assert_eq!(t.name.val().err(), invalid("invalid ASCII", 2));
+    assert_eq!(t.title.val().err(), invalid("invalid UTF-8", 3));
+    assert_eq!(t.le.val().err(), invalid("invalid UTF-16", 0));
+    assert_eq!(t.be.val().err(), invalid("invalid UTF-16", 0));
+}
+
+#[test]
+fn text_view_set() {
+    let mut buf = vec![0xFF; 24];
+    let (mut t, _) = TextViewTest::mut_view(&mut buf);
+    t.name.set(".rdata").unwrap();
+    t.title.set("naïve").unwrap();
+    t.le.set("abc").unwrap();
+    t.be.set("Z").unwrap();
+    assert_eq!(buf, b".rdata\0\0na\xC3\xAFvea\0b\0c\0\0Z\0\0".to_vec());
+
+    let (mut t, _) = TextViewTest::mut_view(&mut buf);
+    assert_eq!(
+        t.name.set("too long!"),
+        Err(crate::Error::Overflow { size: 8, offset: 0 })
+    );
+    assert_eq!(
+        t.name.set("né"),
+        Err(crate::Error::Invalid {
+            reason: "invalid ASCII",
+            offset: 1
+        })
+    );
+    assert_eq!(
+        t.title.set("a\0b"),
+        Err(crate::Error::Invalid {
+            reason: "string contains a NUL",
+            offset: 1
+        })
+    );
+    assert_eq!(
+        t.le.set("abcd"),
+        Err(crate::Error::Overflow { size: 6, offset: 0 })
+    );
+    assert_eq!(
+        t.le.set("ab\0"),
+        Err(crate::Error::Invalid {
+            reason: "string contains a NUL",
+            offset: 4
+        })
+    );
+    assert_eq!(t.name.val().unwrap(), ".rdata");
+    assert_eq!(t.le.val().unwrap(), "abc");
+}
+
+#[derive(MutView)]
+struct AsciiNumViewTest<'a> {
+    pub size: AsciiNumView<'a, Octal, 12>,
+    pub ar_size: AsciiNumView<'a, Decimal, 10>,
+    pub ino: AsciiNumView<'a, Hex, 8>,
+}
+
+#[test]
+fn ascii_num_view_val() {
+    let mut buf = b"   1750 \0\0\0\x00123       0001aBcD".to_vec();
+    let (t, _) = AsciiNumViewTest::mut_view(&mut buf);
+    assert_eq!(t.size.val().unwrap(), 0o1750);
+    assert_eq!(t.ar_size.val().unwrap(), 123);
+    assert_eq!(t.ino.val().unwrap(), 0x1ABCD);
+
+    let mut buf = [&b"00000001758\0"[..], b"1 2       ", b"FFFFFFFF"].concat();
+    let (t, _) = AsciiNumViewTest::mut_view(&mut buf);
+    let invalid = |offset| {
+        Some(crate::Error::Invalid {
+            reason: "invalid digit",
+            offset,
+        })
+    };
+    assert_eq!(t.size.val().err(), invalid(10));
+    assert_eq!(t.ar_size.val().err(), invalid(2));
+    assert_eq!(t.ino.val().unwrap(), 0xFFFF_FFFF);
+
+    let mut buf = vec![b' '; 30];
+    let (t, _) = AsciiNumViewTest::mut_view(&mut buf);
+    assert_eq!(t.ar_size.val().unwrap(), 0);
+
+    let mut buf = b"77777777777777777777777".to_vec();
+    let (v, _) = AsciiNumView::<Octal, 23>::mut_view(&mut buf);
+    assert_eq!(
+        v.val().err(),
+        Some(crate::Error::Overflow {
+            size: 23,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn ascii_num_view_set() {
+    let mut buf = vec![b'?'; 30];
+    let (mut t, _) = AsciiNumViewTest::mut_view(&mut buf);
+    t.size.set(0o644).unwrap();
+    t.ar_size.set(4096).unwrap();
+    t.ino.set(0xBEEF).unwrap();
+    assert_eq!(buf, b"00000000644\x004096      0000BEEF".to_vec());
+
+    let (mut t, _) = AsciiNumViewTest::mut_view(&mut buf);
+    let overflow = |size| Err(crate::Error::Overflow { size, offset: 0 });
+    assert_eq!(t.size.set(0o100_000_000_000), overflow(12));
+    assert_eq!(t.ar_size.set(10_000_000_000), overflow(10));
+    assert_eq!(t.ino.set(0x1_0000_0000), overflow(8));
+    t.size.set(0o77_777_777_777).unwrap();
+    assert_eq!(t.size.val().unwrap(), 0o77_777_777_777);
+    assert_eq!(t.ar_size.val().unwrap(), 4096);
+}
+
+#[test]
+fn varint_val() {
+    let mut buf = vec![0x02, 0xE5, 0x8E, 0x26, 0xC0, 0xBB, 0x78, 0x9D, 0x7F, 0x96, 0x01, 0xFF];
+    let (count, rest): (ByteView<u8>, _) = ByteView::mut_view(&mut buf);
+    let (u, rest) = Uleb128View::mut_view(rest);
+    let (s, rest) = Sleb128View::mut_view(rest);
+    let (s2, rest) = Sleb128View::mut_view(rest);
+    let (p, rest) = ProtobufVarIntView::mut_view(rest);
+    assert_eq!(count.val(), 2);
+    assert_eq!((u.val(), u.encoded_len()), (624485, 3));
+    assert_eq!((s.val(), s.encoded_len()), (-123456, 3));
+    assert_eq!((s2.val(), s2.encoded_len()), (-99, 2));
+    assert_eq!((p.val(), p.encoded_len()), (150, 2));
+    assert_eq!(rest, [0xFF]);
+
+    let mut buf = vec![0x25, 0x7B, 0xBD, 0x9D, 0x7F, 0x3E, 0x7D, 0xC2, 0x19, 0x7C, 0x5E, 0xFF];
+    let (a, rest) = QuicVarIntView::mut_view(&mut buf);
+    let (b, rest) = QuicVarIntView::mut_view(rest);
+    let (c, rest) = QuicVarIntView::mut_view(rest);
+    assert_eq!((a.val(), a.encoded_len()), (37, 1));
+    assert_eq!((b.val(), b.encoded_len()), (15293, 2));
+    assert_eq!((c.val(), c.encoded_len()), (494878333, 4));
+    assert_eq!(rest.len(), 5);
+
+    let mut buf = vec![0xFF; 10];
+    buf.push(0x01);
+    let (u, _) = Uleb128View::mut_view(&mut buf[1..]);
+    assert_eq!(u.val(), u64::MAX);
+}
+
+#[test]
+fn varint_errors() {
+    let mut buf = vec![0x80, 0x80];
+    assert_eq!(
+        Uleb128View::try_mut_view(&mut buf).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 3,
+            available: 2,
+            offset: 0
+        })
+    );
+
+    let mut buf = vec![0xFF; 9];
+    buf.push(0x02);
+    assert_eq!(
+        Uleb128View::try_mut_view(&mut buf).err(),
+        Some(crate::Error::Invalid {
+            reason: "varint does not fit in 64 bits",
+            offset: 9
+        })
+    );
+
+    let mut buf = vec![0xFF; 11];
+    assert!(Sleb128View::try_mut_view(&mut buf).is_err());
+
+    let mut buf = vec![0x80, 0x01];
+    assert_eq!(
+        QuicVarIntView::try_mut_view(&mut buf).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 4,
+            available: 2,
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn varint_set() {
+    let mut buf = vec![0xE5, 0x8E, 0x26, 0x7F, 0x40, 0x25];
+    let (mut u, rest) = Uleb128View::mut_view(&mut buf);
+    let (mut s, rest) = Sleb128View::mut_view(rest);
+    let (mut s2, rest) = Sleb128View::mut_view(rest);
+    let (mut q, _) = QuicVarIntView::mut_view(rest);
+
+    u.set(128).unwrap();
+    s.set(63).unwrap();
+    s2.set(-64).unwrap();
+    q.set(63).unwrap();
+    assert_eq!(
+        u.set(1 << 21),
+        Err(crate::Error::Overflow { size: 3, offset: 0 })
+    );
+    assert!(s.set(64).is_err());
+    assert!(s2.set(-65).is_err());
+    assert!(q.set(64).is_err());
+    assert_eq!((u.val(), s.val(), s2.val(), q.val()), (128, 63, -64, 63));
+    assert_eq!(buf, [0x80, 0x81, 0x00, 0x3F, 0x40, 0x3F]);
+}
+
+#[test]
+fn checksum_internet() {
+    // IPv4 header with the checksum at offset 10
+    let mut buf = vec![
+        0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xB8, 0x61, 0xC0, 0xA8, 0x00,
+        0x01, 0xC0, 0xA8, 0x00, 0xC7, 0xAA,
+    ];
+    let (mut hdr, leftover) =
+        ChecksumView::<InternetChecksum>::mut_view(&mut buf, 0..20, 10);
+    assert_eq!(leftover, [0xAA]);
+    assert_eq!(hdr.val(), 0xB861);
+    assert!(hdr.verify().is_ok());
+
+    let (mut ttl, _): (ByteView<u8>, _) = ByteView::mut_view(&mut hdr.as_mut()[8..]);
+    ttl -= 1;
+    assert_eq!(
+        hdr.verify(),
+        Err(crate::Error::Invalid {
+            reason: "checksum mismatch",
+            offset: 10
+        })
+    );
+
+    hdr.recompute();
+    assert_eq!(hdr.val(), 0xB961);
+    assert!(hdr.is_valid());
+    assert_eq!(&buf[10..12], [0xB9, 0x61]);
+}
+
+#[test]
+fn checksum_crc32_adler32() {
+    // PNG IEND chunk: the CRC covers the type and follows it
+    let mut buf = b"\0\0\0\0IEND\xAE\x42\x60\x82".to_vec();
+    let (mut crc, _) = ChecksumView::<PngCrc32>::mut_view(&mut buf[4..], 0..4, 4);
+    assert_eq!(crc.val(), 0xAE42_6082);
+    assert!(crc.verify().is_ok());
+    crc.as_mut()[0] = b'X';
+    crc.recompute();
+    assert_eq!(crc.compute(), crc.val());
+
+    let mut buf = b"Wikipedia\0\0\0\0".to_vec();
+    let (mut adler, _) = ChecksumView::<Adler32>::mut_view(&mut buf, 0..9, 9);
+    adler.recompute();
+    assert_eq!(adler.val(), 0x11E6_0398);
+    assert_eq!(&buf[9..], [0x11, 0xE6, 0x03, 0x98]);
+
+    // gzip stores the same CRC-32 little endian
+    let mut buf = b"IEND\0\0\0\0".to_vec();
+    let (mut crc, _) = ChecksumView::<Crc32>::mut_view(&mut buf, 0..4, 4);
+    crc.recompute();
+    assert_eq!(&buf[4..], [0x82, 0x60, 0x42, 0xAE]);
+
+    let mut buf = vec![0x00; 3];
+    assert_eq!(
+        ChecksumView::<Crc32>::try_mut_view(&mut buf, 0..2, 2).err(),
+        Some(crate::Error::OutOfBounds {
+            needed: 6,
+            available: 3,
+            offset: 0
+        })
+    );
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let covered = 2..1;
+    assert_eq!(
+        ChecksumView::<Crc32>::try_mut_view(&mut buf, covered, 0).err(),
+        Some(crate::Error::Invalid {
+            reason: "covered range starts after it ends",
+            offset: 0
+        })
+    );
+}
+
+#[test]
+fn checksum_pe() {
+    let mut buf = vec![0x4D, 0x5A, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0x78, 0x01];
+    let (mut pe, _) = ChecksumView::<PeChecksum>::mut_view(&mut buf, 0..9, 4);
+    // 0x5A4D + 0xFFFF + 0x0000 + 0x0001, folded, plus the length of 9
+    assert_eq!(pe.compute(), 0x5A4E + 9);
+    assert!(!pe.is_valid());
+
+    pe.recompute();
+    assert!(pe.verify().is_ok());
+    assert_eq!(&buf[4..8], [0x57, 0x5A, 0x00, 0x00]);
+}
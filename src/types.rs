@@ -3,11 +3,58 @@
 //! Contains all of the custom types implemented by `zordon`.
 
 #[allow(unused_imports)]
-use byteorder::{BigEndian, ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, ByteOrder, LittleEndian, NativeEndian, ReadBytesExt, WriteBytesExt};
+use crate::error::{Error, Result};
 use std::cell::{Ref, RefCell, RefMut};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::convert::TryInto;
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
 use std::rc::Rc;
 
+mod asciinum;
+mod bitfield;
+mod checksum;
+mod enums;
+mod flags;
+mod int;
+mod magic;
+mod offset;
+mod pad;
+mod refs;
+mod seq;
+mod slice;
+mod text;
+mod varint;
+pub use asciinum::*;
+pub use bitfield::*;
+pub use checksum::*;
+pub use enums::*;
+pub use flags::*;
+pub use int::*;
+pub use magic::*;
+pub use offset::*;
+pub use pad::*;
+pub use refs::*;
+pub use seq::*;
+pub use slice::*;
+pub use text::*;
+pub use varint::*;
+
+/// Returns an error if `arr` is shorter than `needed` bytes.
+pub(crate) fn check_len(arr: &[u8], needed: usize) -> Result<()> {
+    if arr.len() < needed {
+        return Err(Error::OutOfBounds {
+            needed,
+            available: arr.len(),
+            offset: 0,
+        });
+    }
+
+    Ok(())
+}
+
 /// For getting/setting single byte values.
 pub trait ModByteView<'a, T> {
     /// Return a copy of the underlying value T.
@@ -25,11 +72,298 @@ pub trait ModMulByteView<'a, T, E> {
 }
 
 /// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a little endian view.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct LitEnd;
 /// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a big endian view.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct BigEnd;
+/// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a view using the
+/// byte order of the host.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NativeEnd;
+/// Alias of [`BigEnd`] for network byte order.
+pub type NetworkEnd = BigEnd;
+
+/// Byte order selected at runtime.
+///
+/// Used with [`MulByteView`] as the E in MulByteView<'a, T, E> when the byte order is only known once
+/// the buffer has been parsed, e.g. from a TIFF `II`/`MM` or ELF `EI_DATA` header field. Unlike [`LitEnd`] and
+/// [`BigEnd`], the value is stored in the view and can be changed with `set_endian`.
+///
+/// Views constructed with `mut_view` default to [`Endian::Little`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// Little endian byte order.
+    #[default]
+    Little,
+    /// Big endian byte order.
+    Big,
+}
+
+/// Implemented by views whose length in bytes is known at compile time.
+///
+/// Structs composed of such views can implement it with [`impl_view_size`](crate::impl_view_size).
+pub trait ViewSize {
+    /// Length of the view in bytes.
+    const SIZE: usize;
+}
+
+/// `Option` fields are defaulted rather than parsed by [`MutView`](crate::MutView), so take up no bytes.
+impl<T> ViewSize for Option<T> {
+    const SIZE: usize = 0;
+}
+
+/// `Option` fields are set to `None` without consuming any bytes, as in [`MutView`](crate::MutView).
+impl<'a, T> ZordonView<'a> for Option<T> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        (None, arr)
+    }
+}
+
+/// Implemented by every fixed size mutable view, tying together construction, size and validation.
+///
+/// Implementing it for a custom type allows the type to be used as a field of a struct deriving
+/// [`MutView`](crate::MutView), or wrapped by [`impl_try_mut_view`](crate::impl_try_mut_view), just like
+/// the built-in types. The trait must be in scope where the struct is declared.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ArrayView, ViewSize, ZordonView};
+/// use zordon::{impl_try_mut_view, Error, Result};
+///
+/// struct Guid<'a>(ArrayView<'a, [u8; 16]>);
+///
+/// impl<'a> ViewSize for Guid<'a> {
+///     const SIZE: usize = 16;
+/// }
+///
+/// impl<'a> ZordonView<'a> for Guid<'a> {
+///     fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+///         let (v, leftover) = ArrayView::mut_view(arr);
+///
+///         (Guid(v), leftover)
+///     }
+///
+///     fn validate(&self) -> Result<()> {
+///         match self.0.as_ref()[8] >> 6 {
+///             0b10 => Ok(()),
+///             _ => Err(Error::Invalid { reason: "not an RFC 4122 GUID", offset: 0 }),
+///         }
+///     }
+/// }
+///
+/// impl_try_mut_view! {
+///     #[derive(MutView)]
+///     struct Entry<'a> {
+///         guid: Guid<'a>,
+///     }
+/// }
+///
+/// let mut input_buf = [0; 16];
+/// assert!(Entry::try_mut_view(&mut input_buf).is_err());
+///
+/// input_buf[8] = 0x80;
+/// assert!(Entry::try_mut_view(&mut input_buf).is_ok());
+/// ```
+pub trait ZordonView<'a>: ViewSize + Sized {
+    /// Returns the view and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::SIZE > arr.len()`
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]);
+
+    /// Checks that the view holds a valid value. Does nothing by default.
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Fallible version of [`ZordonView::mut_view`]. Returns the view and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `Self::SIZE > arr.len()`, or the error returned by
+    /// [`ZordonView::validate`].
+    fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, Self::SIZE)?;
+
+        let (view, leftover) = Self::mut_view(arr);
+        view.validate()?;
+
+        Ok((view, leftover))
+    }
+}
+
+/// Template for implementing ZordonView<'a> by calling the inherent `mut_view` of the target.
+///
+/// For a struct deriving [`MutView`](crate::MutView), [`ViewSize`] must also be implemented, e.g. with
+/// [`impl_view_size`](crate::impl_view_size). The struct can then be nested in other structs via the trait.
+///
+/// ```
+/// use zordon::{impl_view_size, impl_zordon_view, MutView};
+/// use zordon::types::{ByteView, SeqView, ZordonView};
+///
+/// impl_view_size! {
+///     #[derive(MutView)]
+///     struct Record<'a> {
+///         id: ByteView<'a, u8>,
+///     }
+/// }
+///
+/// impl_zordon_view!(Record);
+///
+/// let mut input_buf = [0x01, 0x02];
+/// let (records, _) = SeqView::<Record>::records(&mut input_buf, 2);
+/// assert_eq!(records.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! impl_zordon_view {
+    ($target:ident) => {
+        impl<'a> $crate::types::ZordonView<'a> for $target<'a> {
+            fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+                $target::mut_view(arr)
+            }
+        }
+    };
+    ($target:ident<$($param:ident),*> $(where $($bound:tt)*)?) => {
+        impl<'a, $($param),*> $crate::types::ZordonView<'a> for $target<'a, $($param),*>
+        $(where $($bound)*)?
+        {
+            fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+                $target::mut_view(arr)
+            }
+        }
+    };
+}
+
+/// Implemented by views whose byte order is chosen at runtime with [`Endian`].
+///
+/// [`impl_set_endian`](crate::impl_set_endian) calls it on every field that implements it, and
+/// implements it for the struct, so structs using that macro can themselves be nested.
+pub trait SetEndian {
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    fn set_endian(&mut self, endian: Endian);
+}
+
+/// Wraps a field in [`impl_set_endian`](crate::impl_set_endian), so that [`SetEndian`] is called on
+/// fields implementing it and other fields are skipped.
+#[doc(hidden)]
+pub struct EndianProbe<'r, T>(pub std::cell::RefCell<&'r mut T>);
+
+#[doc(hidden)]
+pub trait SetFieldEndian {
+    fn set_field_endian(&self, endian: Endian);
+}
+
+impl<'r, T: SetEndian> SetFieldEndian for EndianProbe<'r, T> {
+    fn set_field_endian(&self, endian: Endian) {
+        self.0.borrow_mut().set_endian(endian)
+    }
+}
+
+/// Fallback for fields without a runtime byte order. Only found when [`SetFieldEndian`] is not
+/// implemented, as it needs one more autoref.
+#[doc(hidden)]
+pub trait SkipFieldEndian {
+    fn set_field_endian(&self, endian: Endian);
+}
+
+impl<'r, T> SkipFieldEndian for &EndianProbe<'r, T> {
+    fn set_field_endian(&self, _: Endian) {}
+}
+
+/// Implemented by the endian markers to return the byte order they specify.
+pub trait Endianness {
+    /// Returns the byte order of the marker.
+    fn endian(&self) -> Endian;
+}
+
+impl Endianness for LitEnd {
+    fn endian(&self) -> Endian {
+        Endian::Little
+    }
+}
+
+impl Endianness for BigEnd {
+    fn endian(&self) -> Endian {
+        Endian::Big
+    }
+}
+
+impl Endianness for NativeEnd {
+    fn endian(&self) -> Endian {
+        if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        }
+    }
+}
+
+impl Endianness for Endian {
+    fn endian(&self) -> Endian {
+        *self
+    }
+}
+
+/// Implemented by the primitive types that views can read from and write to a buffer.
+pub trait Primitive: Copy {
+    /// Reads a value from the start of `buf` using the byte order `endian`.
+    fn read(buf: &[u8], endian: Endian) -> Self;
+    /// Writes `v` to the start of `buf` using the byte order `endian`.
+    fn write(buf: &mut [u8], endian: Endian, v: Self);
+}
+
+/// Template for implementing Primitive for single byte types.
+#[macro_export]
+macro_rules! impl_primitive_byte {
+    ($type:tt) => {
+        impl Primitive for $type {
+            fn read(buf: &[u8], _endian: Endian) -> Self {
+                buf[0] as $type
+            }
+
+            fn write(buf: &mut [u8], _endian: Endian, v: Self) {
+                buf[0] = v as u8
+            }
+        }
+    };
+}
+
+/// Template for implementing Primitive for multi byte types.
+#[macro_export]
+macro_rules! impl_primitive {
+    ($type:tt, $read:ident, $write:ident) => {
+        impl Primitive for $type {
+            fn read(buf: &[u8], endian: Endian) -> Self {
+                match endian {
+                    Endian::Little => LittleEndian::$read(buf),
+                    Endian::Big => BigEndian::$read(buf),
+                }
+            }
+
+            fn write(buf: &mut [u8], endian: Endian, v: Self) {
+                match endian {
+                    Endian::Little => LittleEndian::$write(buf, v),
+                    Endian::Big => BigEndian::$write(buf, v),
+                }
+            }
+        }
+    };
+}
+
+impl_primitive_byte!(u8);
+impl_primitive_byte!(i8);
+impl_primitive!(u16, read_u16, write_u16);
+impl_primitive!(u32, read_u32, write_u32);
+impl_primitive!(u64, read_u64, write_u64);
+impl_primitive!(u128, read_u128, write_u128);
+impl_primitive!(i16, read_i16, write_i16);
+impl_primitive!(i32, read_i32, write_i32);
+impl_primitive!(i64, read_i64, write_i64);
+impl_primitive!(i128, read_i128, write_i128);
+impl_primitive!(f32, read_f32, write_f32);
+impl_primitive!(f64, read_f64, write_f64);
 
 /// A mutable byte view for type T where the length of the view is always 1.
 ///
@@ -57,6 +391,29 @@ impl<'a, T> ByteView<'a, T> {
             leftover,
         )
     }
+
+    /// Fallible version of [`ByteView::mut_view`]. Returns a [`ByteView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T> ViewSize for ByteView<'a, T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl_zordon_view!(ByteView<T>);
+
+impl<'a, T> ViewOffset for ByteView<'a, T> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
 }
 
 /// Template for implementing ModByteView<'a, _>
@@ -85,10 +442,10 @@ impl_modbyteval!(ByteView, i8);
 pub struct MulByteView<'a, T, E> {
     val: &'a mut [u8],
     _type: std::marker::PhantomData<T>,
-    _endian: std::marker::PhantomData<E>,
+    endian: E,
 }
 
-impl<'a, T, E> MulByteView<'a, T, E> {
+impl<'a, T, E: Default> MulByteView<'a, T, E> {
     /// Returns a [`MulByteView`] and leftover slice.
     ///
     /// # Panics
@@ -101,14 +458,36 @@ impl<'a, T, E> MulByteView<'a, T, E> {
             Self {
                 val,
                 _type: std::marker::PhantomData::<T>,
-                _endian: std::marker::PhantomData::<E>,
+                endian: E::default(),
             },
             leftover,
         )
     }
+
+    /// Fallible version of [`MulByteView::mut_view`]. Returns a [`MulByteView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+
+impl<'a, T, E> ViewSize for MulByteView<'a, T, E> {
+    const SIZE: usize = std::mem::size_of::<T>();
 }
 
+impl_zordon_view!(MulByteView<T, E> where E: Default);
 
+impl<'a, T, E> ViewOffset for MulByteView<'a, T, E> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
 
 /// Template for implementing ModMulByteView<'a, _, _>.
 #[macro_export]
@@ -158,6 +537,104 @@ impl_modmulbyteval!(
 );
 impl_modmulbyteval!(MulByteView, i128, BigEnd, BigEndian, read_i128, write_i128);
 
+impl_modmulbyteval!(MulByteView, u16, NativeEnd, NativeEndian, read_u16, write_u16);
+impl_modmulbyteval!(MulByteView, u32, NativeEnd, NativeEndian, read_u32, write_u32);
+impl_modmulbyteval!(MulByteView, u64, NativeEnd, NativeEndian, read_u64, write_u64);
+impl_modmulbyteval!(
+    MulByteView,
+    u128,
+    NativeEnd,
+    NativeEndian,
+    read_u128,
+    write_u128
+);
+impl_modmulbyteval!(MulByteView, i16, NativeEnd, NativeEndian, read_i16, write_i16);
+impl_modmulbyteval!(MulByteView, i32, NativeEnd, NativeEndian, read_i32, write_i32);
+impl_modmulbyteval!(MulByteView, i64, NativeEnd, NativeEndian, read_i64, write_i64);
+impl_modmulbyteval!(
+    MulByteView,
+    i128,
+    NativeEnd,
+    NativeEndian,
+    read_i128,
+    write_i128
+);
+
+impl_modmulbyteval!(MulByteView, f32, LitEnd, LittleEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f32, BigEnd, BigEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f64, LitEnd, LittleEndian, read_f64, write_f64);
+impl_modmulbyteval!(MulByteView, f64, BigEnd, BigEndian, read_f64, write_f64);
+impl_modmulbyteval!(MulByteView, f32, NativeEnd, NativeEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f64, NativeEnd, NativeEndian, read_f64, write_f64);
+
+impl<'a, T> MulByteView<'a, T, Endian> {
+    /// Returns a [`MulByteView`] using the byte order `endian` and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view_endian(arr: &'a mut [u8], endian: Endian) -> (Self, &'a mut [u8]) {
+        let (mut view, leftover) = Self::mut_view(arr);
+        view.endian = endian;
+
+        (view, leftover)
+    }
+
+    /// Fallible version of [`MulByteView::mut_view_endian`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view_endian(arr: &'a mut [u8], endian: Endian) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view_endian(arr, endian))
+    }
+
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T> SetEndian for MulByteView<'a, T, Endian> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+/// Template for implementing ModMulByteView<'a, _, Endian>.
+#[macro_export]
+macro_rules! impl_modmulbyteval_endian {
+    ($target:tt, $type:tt) => {
+        impl<'a> ModMulByteView<'a, $type, Endian> for $target<'a, $type, Endian> {
+            fn val(&self) -> $type {
+                $type::read(self.val, self.endian)
+            }
+
+            fn set(&mut self, v: $type) {
+                $type::write(self.val, self.endian, v)
+            }
+        }
+    };
+}
+
+impl_modmulbyteval_endian!(MulByteView, u16);
+impl_modmulbyteval_endian!(MulByteView, u32);
+impl_modmulbyteval_endian!(MulByteView, u64);
+impl_modmulbyteval_endian!(MulByteView, u128);
+impl_modmulbyteval_endian!(MulByteView, i16);
+impl_modmulbyteval_endian!(MulByteView, i32);
+impl_modmulbyteval_endian!(MulByteView, i64);
+impl_modmulbyteval_endian!(MulByteView, i128);
+impl_modmulbyteval_endian!(MulByteView, f32);
+impl_modmulbyteval_endian!(MulByteView, f64);
+
 /// Template for implementing oper assign overloading
 #[macro_export]
 macro_rules! impl_oper_assign_overload {
@@ -188,41 +665,225 @@ impl_oper_assign_overload!(AddAssign, Add, add_assign, +, T, E);
 impl_oper_assign_overload!(SubAssign, Sub, sub_assign, -, T, E);
 impl_oper_assign_overload!(MulAssign, Mul, mul_assign, *, T, E);
 impl_oper_assign_overload!(DivAssign, Div, div_assign, /, T, E);
+impl_oper_assign_overload!(RemAssign, Rem, rem_assign, %, T, E);
+impl_oper_assign_overload!(BitAndAssign, BitAnd, bitand_assign, &, T, E);
+impl_oper_assign_overload!(BitOrAssign, BitOr, bitor_assign, |, T, E);
+impl_oper_assign_overload!(BitXorAssign, BitXor, bitxor_assign, ^, T, E);
+impl_oper_assign_overload!(ShlAssign, Shl, shl_assign, <<, T, E);
+impl_oper_assign_overload!(ShrAssign, Shr, shr_assign, >>, T, E);
+
+/// Template for implementing a checked, wrapping and saturating arithmetic method set.
+#[macro_export]
+macro_rules! impl_arith_methods {
+    ($type:tt, $checked:ident, $wrapping:ident, $saturating:ident, $desc:literal) => {
+        #[doc = concat!("Checked ", $desc, ". Sets and returns the new value, or returns `None` and leaves the")]
+        /// buffer untouched if the operation overflows.
+        pub fn $checked(&mut self, rhs: $type) -> Option<$type> {
+            let v = self.val().$checked(rhs)?;
+            self.set(v);
+
+            Some(v)
+        }
+
+        #[doc = concat!("Wrapping ", $desc, ". Sets and returns the new value, wrapping around on overflow.")]
+        pub fn $wrapping(&mut self, rhs: $type) -> $type {
+            let v = self.val().$wrapping(rhs);
+            self.set(v);
 
-/// A mutable array view for type [u8; L] where L is a const.
+            v
+        }
+
+        #[doc = concat!("Saturating ", $desc, ". Sets and returns the new value, clamped to the bounds of T.")]
+        pub fn $saturating(&mut self, rhs: $type) -> $type {
+            let v = self.val().$saturating(rhs);
+            self.set(v);
+
+            v
+        }
+    };
+}
+
+/// Template for implementing checked, wrapping and saturating arithmetic on integer views.
+#[macro_export]
+macro_rules! impl_checked_arith {
+    (ByteView, $type:tt) => {
+        impl<'a> ByteView<'a, $type> {
+            impl_arith_methods!($type, checked_add, wrapping_add, saturating_add, "addition");
+            impl_arith_methods!($type, checked_sub, wrapping_sub, saturating_sub, "subtraction");
+            impl_arith_methods!($type, checked_mul, wrapping_mul, saturating_mul, "multiplication");
+        }
+    };
+    (MulByteView, $type:tt) => {
+        impl<'a, E> MulByteView<'a, $type, E>
+        where
+            MulByteView<'a, $type, E>: ModMulByteView<'a, $type, E>,
+        {
+            impl_arith_methods!($type, checked_add, wrapping_add, saturating_add, "addition");
+            impl_arith_methods!($type, checked_sub, wrapping_sub, saturating_sub, "subtraction");
+            impl_arith_methods!($type, checked_mul, wrapping_mul, saturating_mul, "multiplication");
+        }
+    };
+}
+
+impl_checked_arith!(ByteView, u8);
+impl_checked_arith!(ByteView, i8);
+impl_checked_arith!(MulByteView, u16);
+impl_checked_arith!(MulByteView, u32);
+impl_checked_arith!(MulByteView, u64);
+impl_checked_arith!(MulByteView, u128);
+impl_checked_arith!(MulByteView, i16);
+impl_checked_arith!(MulByteView, i32);
+impl_checked_arith!(MulByteView, i64);
+impl_checked_arith!(MulByteView, i128);
+
+/// A mutable array view for type [T; L] where L is a const.
+///
+/// For [u8; L] the array is accessed by reference. For arrays of other primitive types, elements are
+/// accessed by index and E is an endian marker specifying the byte order of each element.
 #[derive(Debug, PartialEq)]
-pub struct ArrayView<'a, T> {
-    buf: Rc<RefCell<&'a mut [u8]>>,
+pub struct ArrayView<'a, T, E = LitEnd> {
+    buf: &'a mut [u8],
     _type: std::marker::PhantomData<T>,
+    endian: E,
 }
 
-impl<'a, T> ArrayView<'a, T> {
+impl<'a, T, E: Default> ArrayView<'a, T, E> {
     /// Returns an [`ArrayView`] and leftover slice.
     ///
     /// # Panics
     ///
     /// Panics if `T.len() > arr.len()`
     pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
-        let (val, leftover) = arr.split_at_mut(std::mem::size_of::<T>());
+        let (buf, leftover) = arr.split_at_mut(std::mem::size_of::<T>());
 
         (
             Self {
-                buf: Rc::new(RefCell::new(val)),
+                buf,
                 _type: std::marker::PhantomData::<T>,
+                endian: E::default(),
             },
             leftover,
         )
     }
+
+    /// Fallible version of [`ArrayView::mut_view`]. Returns an [`ArrayView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T, E> ViewSize for ArrayView<'a, T, E> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl_zordon_view!(ArrayView<T, E> where E: Default);
+
+impl<'a, T, E> ViewOffset for ArrayView<'a, T, E> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+impl<'a, T, E> ArrayView<'a, T, E> {
+    /// Converts the view into a [`SharedArrayView`] over the same slice.
+    pub fn into_shared(self) -> SharedArrayView<'a, T> {
+        SharedArrayView {
+            buf: Rc::new(RefCell::new(self.buf)),
+            _type: std::marker::PhantomData::<T>,
+        }
+    }
 }
 
-impl<'a, const L: usize> ArrayView<'a, [u8; L]> {
+impl<'a, E, const L: usize> ArrayView<'a, [u8; L], E> {
     /// Returns a mutable reference to the array.
-    pub fn as_mut_ref(&self) -> RefMut<&'a mut [u8]> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_mut(&mut self) -> &mut [u8; L] {
+        // The length of buf is always L, as it was split at size_of::<[u8; L]>().
+        (&mut *self.buf).try_into().unwrap()
+    }
+
+    /// Returns a mutable reference to the array.
+    #[deprecated(note = "use `as_mut` instead")]
+    pub fn as_mut_ref(&mut self) -> &mut [u8; L] {
+        self.as_mut()
+    }
+
+    /// Returns a reference to the array.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8; L] {
+        (&*self.buf).try_into().unwrap()
+    }
+
+    /// Copies bytes from a `&[u8]` source to the underlying array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src.len() < L`
+    pub fn set(&mut self, src: &[u8]) {
+        self.buf.copy_from_slice(&src[..L])
+    }
+}
+
+/// A mutable array view for type [u8; L] that can be shared between owners.
+///
+/// The slice is stored behind a reference counted [`RefCell`], so clones of it can be handed out
+/// with [`SharedArrayView::rc_clone`]. Prefer [`ArrayView`] unless aliasing is required.
+#[derive(Debug, PartialEq)]
+pub struct SharedArrayView<'a, T> {
+    buf: Rc<RefCell<&'a mut [u8]>>,
+    _type: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> SharedArrayView<'a, T> {
+    /// Returns a [`SharedArrayView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = ArrayView::<T>::mut_view(arr);
+
+        (view.into_shared(), leftover)
+    }
+
+    /// Fallible version of [`SharedArrayView::mut_view`]. Returns a [`SharedArrayView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T> ViewSize for SharedArrayView<'a, T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl_zordon_view!(SharedArrayView<T>);
+
+impl<'a, T> ViewOffset for SharedArrayView<'a, T> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(&self.buf.borrow())
+    }
+}
+
+impl<'a, const L: usize> SharedArrayView<'a, [u8; L]> {
+    /// Returns a mutable reference to the array.
+    pub fn as_mut_ref(&self) -> RefMut<'_, &'a mut [u8]> {
         self.buf.borrow_mut()
     }
 
     /// Returns a reference to the array.
-    pub fn as_ref(&self) -> Ref<&'a mut [u8]> {
+    pub fn as_ref(&self) -> Ref<'_, &'a mut [u8]> {
         self.buf.borrow()
     }
 
@@ -232,11 +893,412 @@ impl<'a, const L: usize> ArrayView<'a, [u8; L]> {
     }
 
     /// Copies bytes from a `&[u8]` source to the underlying array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src.len() < L`
     pub fn set(&mut self, src: &[u8]) {
-        let mut dst = self.buf.borrow_mut();
+        self.buf.borrow_mut().copy_from_slice(&src[..L])
+    }
+}
 
-        for i in 0..L {
-            dst[i] = src[i]
-        }
+impl<'a, T> ArrayView<'a, T, Endian> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T> SetEndian for ArrayView<'a, T, Endian> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
     }
 }
+
+/// Template for implementing indexed element access on ArrayView<'a, [_; L], E> and
+/// ArrayRef<'a, [_; L], E>.
+#[macro_export]
+macro_rules! impl_typed_array {
+    (@read $target:ident, $type:tt) => {
+        impl<'a, E: Endianness, const L: usize> $target<'a, [$type; L], E> {
+            /// Returns the number of elements in the array.
+            pub fn len(&self) -> usize {
+                L
+            }
+
+            /// Returns true if the array has no elements.
+            pub fn is_empty(&self) -> bool {
+                L == 0
+            }
+
+            /// Returns a copy of the element at index i, or `None` if i is out of bounds.
+            pub fn get(&self, i: usize) -> Option<$type> {
+                if i >= L {
+                    return None;
+                }
+
+                let size = std::mem::size_of::<$type>();
+
+                Some($type::read(&self.buf[i * size..], self.endian.endian()))
+            }
+
+            /// Returns an iterator over copies of the elements.
+            pub fn iter(&self) -> std::array::IntoIter<$type, L> {
+                let mut arr = [0 as $type; L];
+                self.copy_to(&mut arr);
+
+                IntoIterator::into_iter(arr)
+            }
+
+            /// Copies every element to `dst`.
+            pub fn copy_to(&self, dst: &mut [$type; L]) {
+                let size = std::mem::size_of::<$type>();
+
+                for (i, d) in dst.iter_mut().enumerate() {
+                    *d = $type::read(&self.buf[i * size..], self.endian.endian())
+                }
+            }
+        }
+    };
+    ($type:tt) => {
+        $crate::impl_typed_array!(@read ArrayView, $type);
+        $crate::impl_typed_array!(@read ArrayRef, $type);
+
+        impl<'a, E: Endianness, const L: usize> ArrayView<'a, [$type; L], E> {
+            /// Sets the element at index i to v.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i >= L`
+            pub fn set(&mut self, i: usize, v: $type) {
+                assert!(i < L, "index out of bounds");
+
+                let size = std::mem::size_of::<$type>();
+
+                $type::write(&mut self.buf[i * size..], self.endian.endian(), v)
+            }
+
+            /// Copies every element from `src` to the underlying array.
+            pub fn copy_from(&mut self, src: &[$type; L]) {
+                for (i, v) in src.iter().enumerate() {
+                    self.set(i, *v)
+                }
+            }
+        }
+    };
+}
+
+impl_typed_array!(i8);
+impl_typed_array!(u16);
+impl_typed_array!(u32);
+impl_typed_array!(u64);
+impl_typed_array!(u128);
+impl_typed_array!(i16);
+impl_typed_array!(i32);
+impl_typed_array!(i64);
+impl_typed_array!(i128);
+impl_typed_array!(f32);
+impl_typed_array!(f64);
+
+/// Template for implementing a fallible `try_mut_view` on a struct composed of `zordon` types.
+///
+/// Wraps the struct definition, which is emitted unchanged, so the fields are parsed in the order and
+/// with the types they are declared with, matching the derived `mut_view`. Every field type must have a
+/// `try_mut_view` method or implement [`ZordonView`], so structs using this macro can themselves be
+/// nested. `Option` fields are set to `None` without consuming any bytes, as in the derived `mut_view`.
+///
+/// Errors are returned with an offset relative to the start of the buffer passed to the struct.
+///
+/// Only structs with named fields and a single lifetime parameter are supported. Tuple structs and
+/// structs with other generic parameters, such as `struct Bar<'a, T>`, fail to compile.
+///
+/// ```compile_fail
+/// use zordon::impl_try_mut_view;
+/// use zordon::types::ByteView;
+///
+/// impl_try_mut_view! {
+///     struct Bar<'a, T> {
+///         u8_f: ByteView<'a, u8>,
+///         t: T,
+///     }
+/// }
+/// ```
+///
+/// ```
+/// use zordon::{impl_try_mut_view, MutView, Error};
+/// use zordon::types::{ByteView, MulByteView, BigEnd};
+///
+/// impl_try_mut_view! {
+///     #[derive(MutView)]
+///     struct Example<'a> {
+///         u8_f: ByteView<'a, u8>,
+///         u16_f: MulByteView<'a, u16, BigEnd>,
+///     }
+/// }
+///
+/// let mut input_buf = [0x00, 0x01];
+/// let err = Example::try_mut_view(&mut input_buf).err().unwrap();
+///
+/// assert_eq!(err, Error::OutOfBounds { needed: 2, available: 1, offset: 1 });
+/// ```
+///
+/// It can be combined with [`impl_view_size`](crate::impl_view_size) by nesting the invocations:
+///
+/// ```
+/// use zordon::{impl_try_mut_view, impl_view_size, MutView};
+/// use zordon::types::{ByteView, ViewSize};
+///
+/// impl_try_mut_view! {
+///     impl_view_size! {
+///         #[derive(MutView)]
+///         struct Example<'a> {
+///             u8_f: ByteView<'a, u8>,
+///         }
+///     }
+/// }
+///
+/// let mut input_buf = [0x00];
+/// assert_eq!(Example::SIZE, 1);
+/// assert!(Example::try_mut_view(&mut input_buf).is_ok());
+/// ```
+#[macro_export]
+macro_rules! impl_try_mut_view {
+    (@impl $mac:ident ! { $($inner:tt)* }) => {
+        $crate::impl_try_mut_view!(@impl $($inner)*);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        impl<$lt> $target<$lt> {
+            /// Fallible version of `mut_view`. Returns Self and leftover slice.
+            pub fn try_mut_view(buf: &$lt mut [u8]) -> $crate::Result<(Self, &$lt mut [u8])> {
+                #[allow(unused_imports)]
+                use $crate::types::ZordonView as _;
+
+                let len = buf.len();
+
+                $(
+                    let offset = len - buf.len();
+                    let ($field, buf) = <$ty>::try_mut_view(buf).map_err(|e| e.offset_by(offset))?;
+                )*
+
+                Ok((Self { $($field,)* }, buf))
+            }
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $target<$lt> {
+            $($(#[$fattr])* $fvis $field : $ty),*
+        }
+
+        $crate::impl_try_mut_view!(
+            @impl
+            $(#[$attr])*
+            $vis struct $target<$lt> {
+                $($(#[$fattr])* $fvis $field : $ty),*
+            }
+        );
+    };
+    ($mac:ident ! { $($inner:tt)* }) => {
+        $crate::$mac! { $($inner)* }
+        $crate::impl_try_mut_view!(@impl $($inner)*);
+    };
+    ($($def:tt)*) => {
+        compile_error!("impl_try_mut_view! supports only structs with named fields and a single lifetime");
+    };
+}
+
+/// Template for implementing `set_endian` on a struct containing views that use [`Endian`].
+///
+/// Wraps the struct definition, which is emitted unchanged, and calls [`SetEndian`] on every field
+/// implementing it. Fields using a static endian marker, or no byte order at all, are left untouched.
+/// [`SetEndian`] is also implemented for the struct, so structs using this macro can themselves be
+/// nested.
+///
+/// ```
+/// use zordon::{impl_set_endian, MutView};
+/// use zordon::types::{ByteView, MulByteView, Endian, ModByteView, ModMulByteView};
+///
+/// impl_set_endian! {
+///     #[derive(MutView)]
+///     struct Entry<'a> {
+///         tag: MulByteView<'a, u16, Endian>,
+///         kind: ByteView<'a, u8>,
+///         count: MulByteView<'a, u32, Endian>,
+///     }
+/// }
+///
+/// let mut input_buf = [0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x03];
+/// let (mut entry, _) = Entry::mut_view(&mut input_buf);
+/// entry.set_endian(Endian::Big);
+///
+/// assert_eq!(entry.tag.val(), 0x0001);
+/// assert_eq!(entry.kind.val(), 0x02);
+/// assert_eq!(entry.count.val(), 0x0000_0003);
+/// ```
+#[macro_export]
+macro_rules! impl_set_endian {
+    (@impl $mac:ident ! { $($inner:tt)* }) => {
+        $crate::impl_set_endian!(@impl $($inner)*);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        impl<$lt> $target<$lt> {
+            /// Sets the byte order used by every runtime endian field.
+            pub fn set_endian(&mut self, endian: $crate::types::Endian) {
+                #[allow(unused_imports)]
+                use $crate::types::{SetFieldEndian as _, SkipFieldEndian as _};
+
+                $(
+                    (&$crate::types::EndianProbe(std::cell::RefCell::new(&mut self.$field)))
+                        .set_field_endian(endian);
+                )*
+            }
+        }
+
+        impl<$lt> $crate::types::SetEndian for $target<$lt> {
+            fn set_endian(&mut self, endian: $crate::types::Endian) {
+                Self::set_endian(self, endian)
+            }
+        }
+    };
+    ($mac:ident ! { $($inner:tt)* }) => {
+        $crate::$mac! { $($inner)* }
+        $crate::impl_set_endian!(@impl $($inner)*);
+    };
+    ($($def:tt)*) => {
+        $($def)*
+        $crate::impl_set_endian!(@impl $($def)*);
+    };
+}
+
+/// Template for implementing [`ViewSize`] on a struct composed of `zordon` types.
+///
+/// Wraps the struct definition, which is emitted unchanged, and sums the sizes of its fields. Every
+/// field type must implement [`ViewSize`], so structs using this macro can themselves be nested. Other
+/// macros wrapping a struct definition, such as [`impl_try_mut_view`](crate::impl_try_mut_view), can be
+/// nested inside it.
+///
+/// ```
+/// use zordon::{impl_view_size, MutView};
+/// use zordon::types::{ByteView, MulByteView, ArrayView, BigEnd, ViewSize};
+///
+/// impl_view_size! {
+///     #[derive(MutView)]
+///     struct Header<'a> {
+///         u8_f: ByteView<'a, u8>,
+///         u16_f: MulByteView<'a, u16, BigEnd>,
+///         arr_f: ArrayView<'a, [u8; 3]>,
+///     }
+/// }
+///
+/// const _: () = assert!(Header::SIZE == 6);
+///
+/// let mut input_buf = vec![0; Header::SIZE];
+/// let (_, leftover) = Header::mut_view(&mut input_buf);
+/// assert!(leftover.is_empty());
+/// ```
+#[macro_export]
+macro_rules! impl_view_size {
+    (@impl $mac:ident ! { $($inner:tt)* }) => {
+        $crate::impl_view_size!(@impl $($inner)*);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        impl<$lt> $crate::types::ViewSize for $target<$lt> {
+            const SIZE: usize = 0 $(+ <$ty as $crate::types::ViewSize>::SIZE)*;
+        }
+    };
+    ($mac:ident ! { $($inner:tt)* }) => {
+        $crate::$mac! { $($inner)* }
+        $crate::impl_view_size!(@impl $($inner)*);
+    };
+    ($($def:tt)*) => {
+        $($def)*
+        $crate::impl_view_size!(@impl $($def)*);
+    };
+}
+
+/// Template for implementing [`ViewOffset`] on a struct of views.
+///
+/// Wraps the struct definition, which is emitted unchanged. The struct covers the bytes from the start
+/// of its first field to the end of its last. Every field must implement [`ViewOffset`], so structs
+/// using this macro can themselves be nested. `Option` fields take up no bytes and are skipped.
+///
+/// ```
+/// use zordon::{impl_view_offset, MutView};
+/// use zordon::types::{ByteView, MulByteView, BigEnd, Root, ViewOffset};
+///
+/// impl_view_offset! {
+///     #[derive(MutView)]
+///     struct Entry<'a> {
+///         kind: ByteView<'a, u8>,
+///         len: MulByteView<'a, u16, BigEnd>,
+///     }
+/// }
+///
+/// let mut input_buf = [0x00, 0x00, 0x01, 0x00, 0x02];
+/// let root = Root::new(&input_buf);
+/// let (_, buf) = input_buf.split_at_mut(2);
+/// let (entry, _) = Entry::mut_view(buf);
+///
+/// assert_eq!(entry.offset(root), 2);
+/// assert_eq!(entry.range(root), 2..5);
+/// ```
+#[macro_export]
+macro_rules! impl_view_offset {
+    (@impl $mac:ident ! { $($inner:tt)* }) => {
+        $crate::impl_view_offset!(@impl $($inner)*);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        impl<$lt> $crate::types::ViewOffset for $target<$lt> {
+            fn addr_range(&self) -> std::ops::Range<usize> {
+                let ranges = [$($crate::types::ViewOffset::addr_range(&self.$field)),*];
+                let used = || ranges.iter().filter(|r| !r.is_empty());
+                let start = used().map(|r| r.start).min().unwrap_or(0);
+                let end = used().map(|r| r.end).max().unwrap_or(0);
+
+                start..end
+            }
+        }
+    };
+    ($mac:ident ! { $($inner:tt)* }) => {
+        $crate::$mac! { $($inner)* }
+        $crate::impl_view_offset!(@impl $($inner)*);
+    };
+    ($($def:tt)*) => {
+        $($def)*
+        $crate::impl_view_offset!(@impl $($def)*);
+    };
+}
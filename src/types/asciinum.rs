@@ -0,0 +1,209 @@
+//! Views of integers stored as ASCII text, as used by archive formats.
+
+use super::{addr_range, check_len, ViewOffset, ViewSize, ZordonView};
+use crate::error::{Error, Result};
+
+/// Trait for markers selecting the radix and layout of an [`AsciiNumView`].
+pub trait AsciiRadix {
+    /// The radix the digits are written in.
+    const RADIX: u32;
+    /// Whether `set` pads the digits with leading zeros rather than trailing spaces.
+    const ZERO_PAD: bool;
+    /// Whether `set` reserves the last byte of the field for a NUL terminator.
+    const NUL_TERMINATED: bool;
+}
+
+/// Octal digits, zero-padded and NUL-terminated as in tar headers.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Octal;
+/// Decimal digits, left-aligned and padded with spaces as in ar headers.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Decimal;
+/// Hexadecimal digits, zero-padded to the full width as in cpio headers.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hex;
+
+impl AsciiRadix for Octal {
+    const RADIX: u32 = 8;
+    const ZERO_PAD: bool = true;
+    const NUL_TERMINATED: bool = true;
+}
+
+impl AsciiRadix for Decimal {
+    const RADIX: u32 = 10;
+    const ZERO_PAD: bool = false;
+    const NUL_TERMINATED: bool = false;
+}
+
+impl AsciiRadix for Hex {
+    const RADIX: u32 = 16;
+    const ZERO_PAD: bool = true;
+    const NUL_TERMINATED: bool = false;
+}
+
+/// Returns true if `b` pads or terminates a numeric field.
+fn is_filler(b: u8) -> bool {
+    b == b' ' || b == 0
+}
+
+/// A mutable view of an unsigned integer written as L bytes of ASCII digits in the radix given by R.
+///
+/// Leading spaces, and spaces or NULs after the digits, are skipped when reading. A field that only
+/// holds spaces or NULs reads as zero.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{AsciiNumView, Decimal, Octal};
+///
+/// #[derive(MutView)]
+/// struct TarSize<'a> {
+///     size: AsciiNumView<'a, Octal, 12>,
+///     mtime: AsciiNumView<'a, Octal, 12>,
+/// }
+///
+/// let mut input_buf = *b"00000001750\x0014123456701 ";
+/// let (mut header, _) = TarSize::mut_view(&mut input_buf);
+/// assert_eq!(header.size.val().unwrap(), 1000);
+/// assert_eq!(header.mtime.val().unwrap(), 0o14123456701);
+///
+/// header.size.set(4096).unwrap();
+/// assert_eq!(&input_buf[..12], b"00000010000\0");
+///
+/// let mut input_buf = *b"1234  ";
+/// let (mut len, _) = AsciiNumView::<Decimal, 6>::mut_view(&mut input_buf);
+/// len.set(56).unwrap();
+/// assert_eq!(input_buf, *b"56    ");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct AsciiNumView<'a, R, const L: usize> {
+    buf: &'a mut [u8],
+    _radix: std::marker::PhantomData<R>,
+}
+
+impl<'a, R, const L: usize> AsciiNumView<'a, R, L> {
+    /// Returns an [`AsciiNumView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(L);
+
+        (
+            Self {
+                buf,
+                _radix: std::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`AsciiNumView::mut_view`]. Returns an [`AsciiNumView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `L > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, L)?;
+
+        Ok(Self::mut_view(arr))
+    }
+
+    /// Returns a reference to the text of the field.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<'a, R: AsciiRadix, const L: usize> AsciiNumView<'a, R, L> {
+    /// Returns the value of the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] at the offset of the first byte that is not a digit, space or NUL,
+    /// or [`Error::Overflow`] if the value does not fit in a [`u64`]
+    pub fn val(&self) -> Result<u64> {
+        let start = self.buf.iter().position(|&b| b != b' ').unwrap_or(L);
+        let end = self.buf[start..]
+            .iter()
+            .position(|&b| is_filler(b))
+            .map_or(L, |i| start + i);
+
+        if let Some(i) = self.buf[end..].iter().position(|&b| !is_filler(b)) {
+            return Err(Error::Invalid {
+                reason: "invalid digit",
+                offset: end + i,
+            });
+        }
+
+        self.buf[start..end]
+            .iter()
+            .enumerate()
+            .try_fold(0u64, |v, (i, &b)| {
+                let d = (b as char).to_digit(R::RADIX).ok_or(Error::Invalid {
+                    reason: "invalid digit",
+                    offset: start + i,
+                })?;
+
+                v.checked_mul(u64::from(R::RADIX))
+                    .and_then(|v| v.checked_add(u64::from(d)))
+                    .ok_or(Error::Overflow { size: L, offset: 0 })
+            })
+    }
+
+    /// Writes v to the field in the layout given by R.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if v has too many digits for the field, which is left untouched
+    pub fn set(&mut self, v: u64) -> Result<()> {
+        let width = if R::NUL_TERMINATED { L.saturating_sub(1) } else { L };
+        let mut digits = Vec::with_capacity(width);
+        let mut rest = v;
+
+        loop {
+            let d = (rest % u64::from(R::RADIX)) as u32;
+            digits.push(std::char::from_digit(d, R::RADIX).unwrap().to_ascii_uppercase() as u8);
+            rest /= u64::from(R::RADIX);
+
+            if rest == 0 {
+                break;
+            }
+        }
+
+        if digits.len() > width {
+            return Err(Error::Overflow { size: L, offset: 0 });
+        }
+
+        if R::ZERO_PAD {
+            digits.resize(width, b'0');
+        }
+
+        digits.reverse();
+        let (text, pad) = self.buf.split_at_mut(digits.len());
+        text.copy_from_slice(&digits);
+        pad.fill(b' ');
+
+        if R::NUL_TERMINATED {
+            self.buf[L - 1] = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, R, const L: usize> ViewSize for AsciiNumView<'a, R, L> {
+    const SIZE: usize = L;
+}
+
+impl<'a, R, const L: usize> ZordonView<'a> for AsciiNumView<'a, R, L> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        AsciiNumView::mut_view(arr)
+    }
+}
+
+impl<'a, R, const L: usize> ViewOffset for AsciiNumView<'a, R, L> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
@@ -0,0 +1,260 @@
+//! Views of integers that pack several fields into ranges of bits.
+
+use super::{addr_range, check_len, Endian, Endianness, SetEndian, ViewOffset, ViewSize};
+use crate::error::{Error, Result};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use std::convert::TryFrom;
+
+/// Implemented by the bit numbering markers used with [`BitfieldView`].
+pub trait BitOrder {
+    /// True if bit 0 is the most significant bit of the integer.
+    const MSB_FIRST: bool;
+}
+
+/// Marker type used with [`BitfieldView`] to number bits from the least significant bit.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Lsb0;
+/// Marker type used with [`BitfieldView`] to number bits from the most significant bit.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Msb0;
+
+impl BitOrder for Lsb0 {
+    const MSB_FIRST: bool = false;
+}
+
+impl BitOrder for Msb0 {
+    const MSB_FIRST: bool = true;
+}
+
+/// A named range of bits `start..end` within a [`BitfieldView`], read and written as the unsigned
+/// integer type F.
+///
+/// ```
+/// use zordon::types::{BitfieldView, Bits, BigEnd, Msb0};
+///
+/// const VERSION: Bits<u8> = Bits::new(0, 4);
+/// const IHL: Bits<u8> = Bits::new(4, 8);
+///
+/// let mut input_buf = [0x45];
+/// let (mut v, _): (BitfieldView<u8, BigEnd, Msb0>, _) = BitfieldView::mut_view(&mut input_buf);
+///
+/// assert_eq!(v.val(VERSION), 4);
+/// assert_eq!(v.val(IHL), 5);
+///
+/// v.set(IHL, 6).unwrap();
+/// assert_eq!(input_buf, [0x46]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Bits<F> {
+    start: u32,
+    end: u32,
+    _type: std::marker::PhantomData<F>,
+}
+
+impl<F> Bits<F> {
+    /// Constructs a new [`Bits`] for the range `start..end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or wider than F.
+    pub const fn new(start: u32, end: u32) -> Self {
+        assert!(start < end, "empty bit range");
+        assert!(
+            end - start <= (std::mem::size_of::<F>() * 8) as u32,
+            "bit range is wider than F"
+        );
+
+        Self {
+            start,
+            end,
+            _type: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of bits in the range.
+    pub const fn len(&self) -> u32 {
+        self.end - self.start
+    }
+
+    /// Always returns false, as a bit range is never empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<F> Clone for Bits<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for Bits<F> {}
+
+/// A mutable view of an integer of type T whose bits are accessed through [`Bits`] ranges.
+///
+/// E is an endian marker and O a [`BitOrder`] marker specifying how bits are numbered. Setting a
+/// range never modifies the bits outside of it.
+#[derive(Debug, PartialEq)]
+pub struct BitfieldView<'a, T, E, O = Lsb0> {
+    val: &'a mut [u8],
+    _type: std::marker::PhantomData<T>,
+    _order: std::marker::PhantomData<O>,
+    endian: E,
+}
+
+impl<'a, T, E: Default, O> BitfieldView<'a, T, E, O> {
+    /// Returns a [`BitfieldView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (val, leftover) = arr.split_at_mut(std::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+                _order: std::marker::PhantomData::<O>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`BitfieldView::mut_view`]. Returns a [`BitfieldView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T, E, O> ViewSize for BitfieldView<'a, T, E, O> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+crate::impl_zordon_view!(BitfieldView<T, E, O> where E: Default);
+
+impl<'a, T, E, O> ViewOffset for BitfieldView<'a, T, E, O> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T, O> BitfieldView<'a, T, Endian, O> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T, O> SetEndian for BitfieldView<'a, T, Endian, O> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+impl<'a, T, E, O> BitfieldView<'a, T, E, O>
+where
+    T: Into<u128> + TryFrom<u128>,
+    E: Endianness,
+    O: BitOrder,
+{
+    fn read(&self) -> u128 {
+        match self.endian.endian() {
+            Endian::Little => LittleEndian::read_uint128(self.val, self.val.len()),
+            Endian::Big => BigEndian::read_uint128(self.val, self.val.len()),
+        }
+    }
+
+    fn write(&mut self, v: u128) {
+        let len = self.val.len();
+
+        match self.endian.endian() {
+            Endian::Little => LittleEndian::write_uint128(self.val, v, len),
+            Endian::Big => BigEndian::write_uint128(self.val, v, len),
+        }
+    }
+
+    /// Returns the shift and mask of `bits` relative to the least significant bit.
+    fn shift_mask<F>(&self, bits: Bits<F>) -> (u32, u128) {
+        let width = (self.val.len() * 8) as u32;
+        assert!(bits.end <= width, "bit range is outside of T");
+
+        let shift = if O::MSB_FIRST {
+            width - bits.end
+        } else {
+            bits.start
+        };
+        let mask = u128::MAX >> (128 - bits.len());
+
+        (shift, mask)
+    }
+
+    /// Return a copy of the whole underlying integer.
+    pub fn raw(&self) -> T {
+        T::try_from(self.read()).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Set the whole underlying integer to v.
+    pub fn set_raw(&mut self, v: T) {
+        self.write(v.into())
+    }
+
+    /// Return a copy of the bits in `bits`, shifted down to bit 0.
+    ///
+    /// F must be an unsigned integer, as the bits are not sign-extended.
+    ///
+    /// ```compile_fail
+    /// use zordon::types::{BitfieldView, Bits, BigEnd, Lsb0};
+    ///
+    /// let mut input_buf = [0xFF];
+    /// let (v, _): (BitfieldView<u8, BigEnd, Lsb0>, _) = BitfieldView::mut_view(&mut input_buf);
+    /// v.val(Bits::<i8>::new(0, 8));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range lies outside of T.
+    pub fn val<F: Into<u128> + TryFrom<u128>>(&self, bits: Bits<F>) -> F {
+        let (shift, mask) = self.shift_mask(bits);
+
+        F::try_from((self.read() >> shift) & mask).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Set the bits in `bits` to v, leaving the remaining bits untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] and leaves the buffer untouched if v does not fit in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range lies outside of T.
+    pub fn set<F: Into<u128>>(&mut self, bits: Bits<F>, v: F) -> Result<()> {
+        let (shift, mask) = self.shift_mask(bits);
+        let v = v.into();
+
+        if v & !mask != 0 {
+            return Err(Error::Overflow {
+                size: self.val.len(),
+                offset: 0,
+            });
+        }
+
+        let raw = self.read() & !(mask << shift);
+        self.write(raw | (v << shift));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+//! Views of checksums together with the bytes they cover.
+
+use super::{addr_range, check_len, BigEnd, Endianness, LitEnd, Primitive, ViewOffset};
+use crate::error::{Error, Result};
+use std::ops::Range;
+
+/// Trait for markers selecting the algorithm of a [`ChecksumView`].
+pub trait ChecksumAlgorithm {
+    /// The type of the checksum.
+    type Value: Primitive + PartialEq;
+    /// The byte order the checksum is stored in.
+    type Endian: Endianness + Default;
+
+    /// Returns the checksum of `data`, with the bytes in `hole` taken as zero.
+    fn compute(data: &[u8], hole: Range<usize>) -> Self::Value;
+}
+
+/// The RFC 1071 one's-complement sum used by IPv4, UDP and TCP. Stored big endian.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct InternetChecksum;
+/// The CRC-32 used by zip and gzip. Stored little endian.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Crc32;
+/// The CRC-32 used by PNG chunks. Stored big endian.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PngCrc32;
+/// The Adler-32 checksum used by zlib. Stored big endian.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Adler32;
+/// The checksum of the PE optional header, covering the whole image. Stored little endian.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PeChecksum;
+
+/// Returns the bytes of `data`, with the bytes in `hole` replaced by zero.
+fn with_hole(data: &[u8], hole: Range<usize>) -> impl Iterator<Item = u8> + '_ {
+    data.iter()
+        .enumerate()
+        .map(move |(i, &b)| if hole.contains(&i) { 0 } else { b })
+}
+
+/// Returns the sum of the 16 bit words of `data`, with the carries folded back in.
+///
+/// `word` combines the two bytes of each word, so that the byte order can be chosen. A trailing odd
+/// byte is padded with zero.
+fn folded_sum(data: &[u8], hole: Range<usize>, word: fn([u8; 2]) -> u16) -> u16 {
+    let mut bytes = with_hole(data, hole);
+    let mut sum = 0u32;
+
+    while let Some(hi) = bytes.next() {
+        let lo = bytes.next().unwrap_or(0);
+        sum += u32::from(word([hi, lo]));
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+
+    sum as u16
+}
+
+impl ChecksumAlgorithm for InternetChecksum {
+    type Value = u16;
+    type Endian = BigEnd;
+
+    fn compute(data: &[u8], hole: Range<usize>) -> u16 {
+        !folded_sum(data, hole, u16::from_be_bytes)
+    }
+}
+
+/// Lookup table for the reflected CRC-32 polynomial 0xEDB88320.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+
+        table[i] = c;
+        i += 1;
+    }
+
+    table
+};
+
+/// Returns the CRC-32 of `data`, with the bytes in `hole` taken as zero.
+fn crc32(data: &[u8], hole: Range<usize>) -> u32 {
+    !with_hole(data, hole).fold(!0, |c, b| {
+        CRC32_TABLE[((c ^ u32::from(b)) & 0xFF) as usize] ^ (c >> 8)
+    })
+}
+
+impl ChecksumAlgorithm for Crc32 {
+    type Value = u32;
+    type Endian = LitEnd;
+
+    fn compute(data: &[u8], hole: Range<usize>) -> u32 {
+        crc32(data, hole)
+    }
+}
+
+impl ChecksumAlgorithm for PngCrc32 {
+    type Value = u32;
+    type Endian = BigEnd;
+
+    fn compute(data: &[u8], hole: Range<usize>) -> u32 {
+        crc32(data, hole)
+    }
+}
+
+impl ChecksumAlgorithm for Adler32 {
+    type Value = u32;
+    type Endian = BigEnd;
+
+    fn compute(data: &[u8], hole: Range<usize>) -> u32 {
+        const MOD: u32 = 65521;
+
+        let (a, b) = with_hole(data, hole).fold((1, 0), |(a, b), byte| {
+            let a = (a + u32::from(byte)) % MOD;
+            (a, (b + a) % MOD)
+        });
+
+        b << 16 | a
+    }
+}
+
+impl ChecksumAlgorithm for PeChecksum {
+    type Value = u32;
+    type Endian = LitEnd;
+
+    fn compute(data: &[u8], hole: Range<usize>) -> u32 {
+        u32::from(folded_sum(data, hole, u16::from_le_bytes)).wrapping_add(data.len() as u32)
+    }
+}
+
+/// A mutable view of a region holding a checksum computed by C, and the bytes it covers.
+///
+/// The byte order of the checksum is fixed by C, as each algorithm is defined with one.
+///
+/// `covered` and `field`, the offset of the checksum, are relative to the start of the view. When
+/// the checksum is stored inside the bytes it covers, as in IPv4 headers, its own bytes are taken
+/// as zero. As the view holds the whole region, the fields inside it are parsed from
+/// [`ChecksumView::as_mut`], and the checksum recomputed once they have been dropped.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, ChecksumView, InternetChecksum, ModByteView};
+///
+/// #[derive(MutView)]
+/// struct Header<'a> {
+///     ttl: ByteView<'a, u8>,
+///     proto: ByteView<'a, u8>,
+/// }
+///
+/// let mut input_buf = [0x40, 0x06, 0xBF, 0xF9, 0xFF];
+/// let (mut region, _) =
+///     ChecksumView::<InternetChecksum>::mut_view(&mut input_buf, 0..4, 2);
+/// assert!(region.verify().is_ok());
+///
+/// let (mut header, _) = Header::mut_view(region.as_mut());
+/// header.ttl -= 1;
+/// assert!(region.verify().is_err());
+///
+/// region.recompute();
+/// assert!(region.verify().is_ok());
+/// assert_eq!(input_buf, [0x3F, 0x06, 0xC0, 0xF9, 0xFF]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ChecksumView<'a, C> {
+    buf: &'a mut [u8],
+    covered: Range<usize>,
+    field: usize,
+    _alg: std::marker::PhantomData<C>,
+}
+
+impl<'a, C: ChecksumAlgorithm> ChecksumView<'a, C> {
+    /// Returns the number of bytes needed for the covered range and the checksum.
+    fn needed(covered: &Range<usize>, field: usize) -> usize {
+        covered
+            .end
+            .max(field.saturating_add(std::mem::size_of::<C::Value>()))
+    }
+
+    /// Returns a [`ChecksumView`] of the region holding the `covered` range and the checksum at
+    /// offset `field`, and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the covered range or the checksum is out of bounds of `arr`, or the covered range
+    /// starts after it ends
+    pub fn mut_view(
+        arr: &'a mut [u8],
+        covered: Range<usize>,
+        field: usize,
+    ) -> (Self, &'a mut [u8]) {
+        assert!(
+            covered.start <= covered.end,
+            "covered range starts after it ends"
+        );
+
+        let (buf, leftover) = arr.split_at_mut(Self::needed(&covered, field));
+
+        (
+            Self {
+                buf,
+                covered,
+                field,
+                _alg: std::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`ChecksumView::mut_view`]. Returns a [`ChecksumView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] if the covered range starts after it ends, or
+    /// [`Error::OutOfBounds`] if the covered range or the checksum is out of bounds of `arr`
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        covered: Range<usize>,
+        field: usize,
+    ) -> Result<(Self, &'a mut [u8])> {
+        if covered.start > covered.end {
+            return Err(Error::Invalid {
+                reason: "covered range starts after it ends",
+                offset: 0,
+            });
+        }
+
+        check_len(arr, Self::needed(&covered, field))?;
+
+        Ok(Self::mut_view(arr, covered, field))
+    }
+
+    /// Returns the range of the checksum within the view.
+    fn field_range(&self) -> Range<usize> {
+        self.field..self.field + std::mem::size_of::<C::Value>()
+    }
+
+    /// Returns a reference to the covered bytes.
+    pub fn covered(&self) -> &[u8] {
+        &self.buf[self.covered.clone()]
+    }
+
+    /// Returns a reference to the whole region.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns a mutable reference to the whole region, for parsing the fields inside it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_mut(&mut self) -> &mut [u8] {
+        self.buf
+    }
+
+    /// Returns the stored checksum.
+    pub fn val(&self) -> C::Value {
+        C::Value::read(&self.buf[self.field_range()], C::Endian::default().endian())
+    }
+
+    /// Returns the checksum of the covered bytes.
+    pub fn compute(&self) -> C::Value {
+        let field = self.field_range();
+        let start = self.covered.start;
+        let hole = field.start.max(start) - start..field.end.max(start) - start;
+
+        C::compute(self.covered(), hole)
+    }
+
+    /// Returns true if the stored checksum matches the covered bytes.
+    pub fn is_valid(&self) -> bool {
+        self.val() == self.compute()
+    }
+
+    /// Checks that the stored checksum matches the covered bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] at the offset of the checksum if it does not match
+    pub fn verify(&self) -> Result<()> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        Err(Error::Invalid {
+            reason: "checksum mismatch",
+            offset: self.field,
+        })
+    }
+
+    /// Writes the checksum of the covered bytes.
+    pub fn recompute(&mut self) {
+        let v = self.compute();
+        let field = self.field_range();
+
+        C::Value::write(&mut self.buf[field], C::Endian::default().endian(), v)
+    }
+}
+
+impl<'a, C> ViewOffset for ChecksumView<'a, C> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
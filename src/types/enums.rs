@@ -0,0 +1,167 @@
+//! Views of integers holding the discriminant of a user defined enum.
+
+use super::{
+    addr_range, check_len, Endian, Endianness, Primitive, SetEndian, ViewOffset, ViewSize,
+};
+use crate::error::Result;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The raw value of an [`EnumView`] that does not match any discriminant of the enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unknown<R>(pub R);
+
+impl<R: fmt::LowerHex> fmt::Display for Unknown<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown discriminant {:#x}", self.0)
+    }
+}
+
+impl<R: fmt::Debug + fmt::LowerHex> std::error::Error for Unknown<R> {}
+
+/// A mutable view of an enum T stored as a raw integer of type R.
+///
+/// T must implement `TryFrom<R>` to be read and `Into<R>` to be written. E is an endian marker.
+/// Values that are not valid discriminants can still be accessed with `raw`/`set_raw`.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use zordon::types::{EnumView, LitEnd, Unknown};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Machine {
+///     I386 = 0x014c,
+///     Amd64 = 0x8664,
+/// }
+///
+/// impl TryFrom<u16> for Machine {
+///     type Error = ();
+///
+///     fn try_from(v: u16) -> Result<Self, ()> {
+///         match v {
+///             0x014c => Ok(Machine::I386),
+///             0x8664 => Ok(Machine::Amd64),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// impl From<Machine> for u16 {
+///     fn from(m: Machine) -> u16 {
+///         m as u16
+///     }
+/// }
+///
+/// let mut input_buf = [0x4c, 0x01];
+/// let (mut machine, _): (EnumView<Machine, u16, LitEnd>, _) = EnumView::mut_view(&mut input_buf);
+/// assert_eq!(machine.val(), Ok(Machine::I386));
+///
+/// machine.set_raw(0xFFFF);
+/// assert_eq!(machine.val(), Err(Unknown(0xFFFF)));
+///
+/// machine.set(Machine::Amd64);
+/// assert_eq!(input_buf, [0x64, 0x86]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct EnumView<'a, T, R, E> {
+    val: &'a mut [u8],
+    _type: std::marker::PhantomData<T>,
+    _raw: std::marker::PhantomData<R>,
+    endian: E,
+}
+
+impl<'a, T, R, E: Default> EnumView<'a, T, R, E> {
+    /// Returns an [`EnumView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (val, leftover) = arr.split_at_mut(std::mem::size_of::<R>());
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+                _raw: std::marker::PhantomData::<R>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`EnumView::mut_view`]. Returns an [`EnumView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `R.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<R>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T, R, E> ViewSize for EnumView<'a, T, R, E> {
+    const SIZE: usize = std::mem::size_of::<R>();
+}
+
+crate::impl_zordon_view!(EnumView<T, R, E> where E: Default);
+
+impl<'a, T, R, E> ViewOffset for EnumView<'a, T, R, E> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T, R> EnumView<'a, T, R, Endian> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T, R> SetEndian for EnumView<'a, T, R, Endian> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+impl<'a, T, R: Primitive, E: Endianness> EnumView<'a, T, R, E> {
+    /// Return a copy of the raw underlying value.
+    pub fn raw(&self) -> R {
+        R::read(self.val, self.endian.endian())
+    }
+
+    /// Set the raw underlying value to v.
+    pub fn set_raw(&mut self, v: R) {
+        R::write(self.val, self.endian.endian(), v)
+    }
+
+    /// Return the underlying value as T.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Unknown`] holding the raw value if it is not a valid discriminant of T.
+    pub fn val(&self) -> std::result::Result<T, Unknown<R>>
+    where
+        T: TryFrom<R>,
+    {
+        let raw = self.raw();
+
+        T::try_from(raw).map_err(|_| Unknown(raw))
+    }
+
+    /// Set the underlying value to the discriminant of v.
+    pub fn set(&mut self, v: T)
+    where
+        T: Into<R>,
+    {
+        self.set_raw(v.into())
+    }
+}
@@ -0,0 +1,216 @@
+//! Views of integers used as bitmasks of flags.
+
+use super::{
+    addr_range, check_len, Endian, Endianness, Primitive, SetEndian, ViewOffset, ViewSize,
+};
+use crate::error::Result;
+use std::convert::TryFrom;
+use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign};
+
+/// A mutable view of a bitmask stored as an unsigned integer of type T.
+///
+/// E is an endian marker. F is the type flags are passed and returned as, which defaults to T. A
+/// user defined flags type can be used by implementing `Into<T>` and `From<T>` for it.
+///
+/// ```
+/// use zordon::types::{FlagsView, LitEnd};
+///
+/// const EXECUTABLE_IMAGE: u16 = 0x0002;
+/// const LARGE_ADDRESS_AWARE: u16 = 0x0020;
+/// const DLL: u16 = 0x2000;
+///
+/// let mut input_buf = [0x22, 0x00];
+/// let (mut characteristics, _): (FlagsView<u16, LitEnd>, _) = FlagsView::mut_view(&mut input_buf);
+///
+/// assert!(characteristics.contains(EXECUTABLE_IMAGE | LARGE_ADDRESS_AWARE));
+/// assert!(!characteristics.contains(DLL));
+///
+/// characteristics.insert(DLL);
+/// characteristics ^= LARGE_ADDRESS_AWARE;
+/// assert_eq!(characteristics.iter().collect::<Vec<_>>(), vec![EXECUTABLE_IMAGE, DLL]);
+/// assert_eq!(input_buf, [0x02, 0x20]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct FlagsView<'a, T, E, F = T> {
+    val: &'a mut [u8],
+    _type: std::marker::PhantomData<T>,
+    _flags: std::marker::PhantomData<F>,
+    endian: E,
+}
+
+impl<'a, T, E: Default, F> FlagsView<'a, T, E, F> {
+    /// Returns a [`FlagsView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (val, leftover) = arr.split_at_mut(std::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+                _flags: std::marker::PhantomData::<F>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`FlagsView::mut_view`]. Returns a [`FlagsView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T, E, F> ViewSize for FlagsView<'a, T, E, F> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+crate::impl_zordon_view!(FlagsView<T, E, F> where E: Default);
+
+impl<'a, T, E, F> ViewOffset for FlagsView<'a, T, E, F> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T, F> FlagsView<'a, T, Endian, F> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T, F> SetEndian for FlagsView<'a, T, Endian, F> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+impl<'a, T, E, F> FlagsView<'a, T, E, F>
+where
+    T: Primitive + Into<u128> + TryFrom<u128>,
+    E: Endianness,
+    F: Into<T> + From<T>,
+{
+    fn bits(&self) -> u128 {
+        self.raw().into()
+    }
+
+    fn set_bits(&mut self, v: u128) {
+        // v is always built from values of type T, so it can not overflow T.
+        self.set_raw(T::try_from(v).unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Return a copy of the raw underlying value.
+    pub fn raw(&self) -> T {
+        T::read(self.val, self.endian.endian())
+    }
+
+    /// Set the raw underlying value to v.
+    pub fn set_raw(&mut self, v: T) {
+        T::write(self.val, self.endian.endian(), v)
+    }
+
+    /// Return the underlying value as F.
+    pub fn val(&self) -> F {
+        F::from(self.raw())
+    }
+
+    /// Set the underlying value to v.
+    pub fn set(&mut self, v: F) {
+        self.set_raw(v.into())
+    }
+
+    /// Returns true if all of the flags in `flags` are set.
+    pub fn contains(&self, flags: F) -> bool {
+        let flags = flags.into().into();
+
+        self.bits() & flags == flags
+    }
+
+    /// Sets the flags in `flags`.
+    pub fn insert(&mut self, flags: F) {
+        self.set_bits(self.bits() | flags.into().into())
+    }
+
+    /// Clears the flags in `flags`.
+    pub fn remove(&mut self, flags: F) {
+        self.set_bits(self.bits() & !flags.into().into())
+    }
+
+    /// Toggles the flags in `flags`.
+    pub fn toggle(&mut self, flags: F) {
+        self.set_bits(self.bits() ^ flags.into().into())
+    }
+
+    /// Returns an iterator over each set bit, from least to most significant, as F.
+    pub fn iter(&self) -> FlagsIter<T, F> {
+        FlagsIter {
+            bits: self.bits(),
+            _type: std::marker::PhantomData::<T>,
+            _flags: std::marker::PhantomData::<F>,
+        }
+    }
+}
+
+/// Iterator over the set bits of a [`FlagsView`].
+#[derive(Debug)]
+pub struct FlagsIter<T, F> {
+    bits: u128,
+    _type: std::marker::PhantomData<T>,
+    _flags: std::marker::PhantomData<F>,
+}
+
+impl<T, F> Iterator for FlagsIter<T, F>
+where
+    T: TryFrom<u128>,
+    F: From<T>,
+{
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let bit = self.bits & self.bits.wrapping_neg();
+        self.bits &= !bit;
+
+        Some(F::from(T::try_from(bit).unwrap_or_else(|_| unreachable!())))
+    }
+}
+
+/// Template for implementing bitwise oper assign overloading on FlagsView
+#[macro_export]
+macro_rules! impl_flags_oper_assign_overload {
+    ($oper_name:ident, $fname:ident, $oper:tt) => {
+        impl<'a, T, E, F> $oper_name<F> for FlagsView<'a, T, E, F>
+        where
+            T: Primitive + Into<u128> + TryFrom<u128>,
+            E: Endianness,
+            F: Into<T> + From<T>,
+        {
+            fn $fname(&mut self, rhs: F) {
+                self.set_bits(self.bits() $oper rhs.into().into())
+            }
+        }
+    };
+}
+
+impl_flags_oper_assign_overload!(BitAndAssign, bitand_assign, &);
+impl_flags_oper_assign_overload!(BitOrAssign, bitor_assign, |);
+impl_flags_oper_assign_overload!(BitXorAssign, bitxor_assign, ^);
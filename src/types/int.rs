@@ -0,0 +1,179 @@
+//! Integer views whose width in bytes differs from the width of the Rust type.
+
+use super::{
+    addr_range, check_len, Endian, Endianness, SetEndian, ViewOffset, ViewSize, ZordonView,
+};
+use crate::error::{Error, Result};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+/// A mutable view of an N byte integer, read into and written from the wider type T.
+///
+/// Signed values are sign-extended. Check the `val`/`set` implementations for valid T monomorphisms
+/// and the type aliases below for the common widths.
+///
+/// N must be at least 1 and no wider than T, which is checked at compile time.
+///
+/// ```compile_fail
+/// use zordon::types::{IntView, LitEnd};
+///
+/// let mut input_buf = [0x01, 0x02, 0x03];
+/// let (_v, _): (IntView<u16, LitEnd, 3>, _) = IntView::mut_view(&mut input_buf);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct IntView<'a, T, E, const N: usize> {
+    val: &'a mut [u8],
+    _type: std::marker::PhantomData<T>,
+    endian: E,
+}
+
+/// A 3 byte unsigned integer view.
+pub type U24View<'a, E> = IntView<'a, u32, E, 3>;
+/// A 3 byte signed integer view.
+pub type I24View<'a, E> = IntView<'a, i32, E, 3>;
+/// A 5 byte unsigned integer view.
+pub type U40View<'a, E> = IntView<'a, u64, E, 5>;
+/// A 5 byte signed integer view.
+pub type I40View<'a, E> = IntView<'a, i64, E, 5>;
+/// A 6 byte unsigned integer view.
+pub type U48View<'a, E> = IntView<'a, u64, E, 6>;
+/// A 6 byte signed integer view.
+pub type I48View<'a, E> = IntView<'a, i64, E, 6>;
+/// A 7 byte unsigned integer view.
+pub type U56View<'a, E> = IntView<'a, u64, E, 7>;
+/// A 7 byte signed integer view.
+pub type I56View<'a, E> = IntView<'a, i64, E, 7>;
+
+impl<'a, T, E: Default, const N: usize> IntView<'a, T, E, N> {
+    /// Fails to compile if N is zero or wider than T.
+    const WIDTH_CHECK: () = assert!(
+        N > 0 && N <= std::mem::size_of::<T>(),
+        "N must be between 1 and the width of T"
+    );
+
+    /// Returns an [`IntView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let () = Self::WIDTH_CHECK;
+
+        let (val, leftover) = arr.split_at_mut(N);
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`IntView::mut_view`]. Returns an [`IntView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `N > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, N)?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, T, E, const N: usize> ViewSize for IntView<'a, T, E, N> {
+    const SIZE: usize = N;
+}
+
+impl<'a, T, E: Default, const N: usize> ZordonView<'a> for IntView<'a, T, E, N> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        IntView::mut_view(arr)
+    }
+}
+
+impl<'a, T, E, const N: usize> ViewOffset for IntView<'a, T, E, N> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T, const N: usize> IntView<'a, T, Endian, N> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T, const N: usize> SetEndian for IntView<'a, T, Endian, N> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+/// Returns true if the unsigned value `v` fits in `n` bytes.
+fn fits_unsigned(v: u128, n: usize) -> bool {
+    n >= 16 || v >> (n * 8) == 0
+}
+
+/// Returns true if the signed value `v` fits in `n` bytes.
+fn fits_signed(v: i128, n: usize) -> bool {
+    if n >= 16 {
+        return true;
+    }
+
+    let max = (1i128 << (n * 8 - 1)) - 1;
+    v >= -max - 1 && v <= max
+}
+
+/// Template for implementing val/set on IntView<'a, _, E, N>.
+#[macro_export]
+macro_rules! impl_intval {
+    ($type:tt, $wide:tt, $fits:ident, $read:ident, $write:ident) => {
+        impl<'a, E: Endianness, const N: usize> IntView<'a, $type, E, N> {
+            /// Return a copy of the underlying value, extended to T.
+            pub fn val(&self) -> $type {
+                let v = match self.endian.endian() {
+                    Endian::Little => LittleEndian::$read(self.val, N),
+                    Endian::Big => BigEndian::$read(self.val, N),
+                };
+
+                v as $type
+            }
+
+            /// Set the underlying value to v.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::Overflow`] and leaves the buffer untouched if v does not fit in N bytes.
+            pub fn set(&mut self, v: $type) -> Result<()> {
+                let v = v as $wide;
+
+                if !$fits(v, N) {
+                    return Err(Error::Overflow { size: N, offset: 0 });
+                }
+
+                match self.endian.endian() {
+                    Endian::Little => LittleEndian::$write(self.val, v, N),
+                    Endian::Big => BigEndian::$write(self.val, v, N),
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_intval!(u16, u128, fits_unsigned, read_uint128, write_uint128);
+impl_intval!(u32, u128, fits_unsigned, read_uint128, write_uint128);
+impl_intval!(u64, u128, fits_unsigned, read_uint128, write_uint128);
+impl_intval!(u128, u128, fits_unsigned, read_uint128, write_uint128);
+impl_intval!(i16, i128, fits_signed, read_int128, write_int128);
+impl_intval!(i32, i128, fits_signed, read_int128, write_int128);
+impl_intval!(i64, i128, fits_signed, read_int128, write_int128);
+impl_intval!(i128, i128, fits_signed, read_int128, write_int128);
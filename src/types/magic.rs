@@ -0,0 +1,173 @@
+//! Views of constant signatures, which are checked when parsing.
+
+use super::{addr_range, check_len, ViewOffset, ViewSize, ZordonView};
+use crate::error::{Error, Result};
+
+/// Trait for types naming the expected value of a [`MagicView`].
+///
+/// Usually implemented with [`impl_magic`](crate::impl_magic).
+pub trait Magic {
+    /// The expected bytes, in the order they appear in the buffer.
+    const BYTES: &'static [u8];
+}
+
+/// A mutable view of a magic value or signature, whose expected bytes are given by M.
+///
+/// The infallible `mut_view` does not check the bytes, so that damaged files can still be parsed
+/// and repaired with [`MagicView::restore`]. The fallible `try_mut_view` returns
+/// [`Error::BadMagic`] if they don't match.
+///
+/// ```
+/// use zordon::{impl_magic, impl_try_mut_view, MutView, Error};
+/// use zordon::types::{MagicView, MulByteView, LitEnd, ModMulByteView};
+///
+/// impl_magic!(struct Mz = b"MZ");
+///
+/// impl_try_mut_view! {
+///     #[derive(MutView)]
+///     struct DosHeader<'a> {
+///         magic: MagicView<'a, Mz>,
+///         last_page: MulByteView<'a, u16, LitEnd>,
+///     }
+/// }
+///
+/// let mut input_buf = [b'M', b'Z', 0x90, 0x00];
+/// let (header, _) = DosHeader::try_mut_view(&mut input_buf).unwrap();
+/// assert_eq!(header.last_page.val(), 0x90);
+///
+/// let mut input_buf = [b'Z', b'M', 0x90, 0x00];
+/// assert!(matches!(
+///     DosHeader::try_mut_view(&mut input_buf),
+///     Err(Error::BadMagic { offset: 0, .. })
+/// ));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct MagicView<'a, M> {
+    buf: &'a mut [u8],
+    _magic: std::marker::PhantomData<M>,
+}
+
+impl<'a, M: Magic> MagicView<'a, M> {
+    /// Returns a [`MagicView`] and leftover slice. The bytes are not checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M::BYTES.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(M::BYTES.len());
+
+        (
+            Self {
+                buf,
+                _magic: std::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`MagicView::mut_view`]. Returns a [`MagicView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `M::BYTES.len() > arr.len()`, or [`Error::BadMagic`] if the
+    /// bytes don't match `M::BYTES`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, M::BYTES.len())?;
+
+        let (view, leftover) = Self::mut_view(arr);
+        view.verify()?;
+
+        Ok((view, leftover))
+    }
+
+    /// Returns a reference to the bytes held by the view.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns true if the view holds the expected magic value.
+    pub fn is_valid(&self) -> bool {
+        self.buf == M::BYTES
+    }
+
+    /// Checks that the view holds the expected magic value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BadMagic`] if the bytes don't match `M::BYTES`
+    pub fn verify(&self) -> Result<()> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        Err(Error::BadMagic {
+            expected: M::BYTES,
+            found: self.buf.to_vec(),
+            offset: 0,
+        })
+    }
+
+    /// Writes the expected magic value to the view.
+    pub fn restore(&mut self) {
+        self.buf.copy_from_slice(M::BYTES)
+    }
+}
+
+impl<'a, M: Magic> ViewSize for MagicView<'a, M> {
+    const SIZE: usize = M::BYTES.len();
+}
+
+impl<'a, M: Magic> ZordonView<'a> for MagicView<'a, M> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        MagicView::mut_view(arr)
+    }
+
+    fn validate(&self) -> Result<()> {
+        self.verify()
+    }
+}
+
+impl<'a, M> ViewOffset for MagicView<'a, M> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+/// Template for declaring a [`Magic`] type for use with [`MagicView`].
+///
+/// The value is either a byte string, or an integer followed by the byte order it is stored in.
+///
+/// ```
+/// use zordon::impl_magic;
+/// use zordon::types::Magic;
+///
+/// impl_magic!(pub struct Elf = b"\x7fELF");
+/// impl_magic!(pub struct PeSignature = 0x0000_4550u32, LitEnd);
+/// impl_magic!(struct Jpeg = 0xFFD8u16, BigEnd);
+///
+/// assert_eq!(Elf::BYTES, b"\x7fELF");
+/// assert_eq!(PeSignature::BYTES, b"PE\0\0");
+/// assert_eq!(Jpeg::BYTES, [0xFF, 0xD8]);
+/// ```
+#[macro_export]
+macro_rules! impl_magic {
+    (@marker $(#[$attr:meta])* $vis:vis $name:ident = $bytes:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq)]
+        $vis struct $name;
+
+        impl $crate::types::Magic for $name {
+            const BYTES: &'static [u8] = $bytes;
+        }
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $bytes:literal) => {
+        $crate::impl_magic!(@marker $(#[$attr])* $vis $name = $bytes);
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $val:literal, LitEnd) => {
+        $crate::impl_magic!(@marker $(#[$attr])* $vis $name = &$val.to_le_bytes());
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $val:literal, BigEnd) => {
+        $crate::impl_magic!(@marker $(#[$attr])* $vis $name = &$val.to_be_bytes());
+    };
+}
@@ -0,0 +1,112 @@
+//! Offsets of views relative to the buffer they were parsed from.
+
+use std::ops::Range;
+
+/// Returns the range of addresses covered by `buf`.
+pub(crate) fn addr_range(buf: &[u8]) -> Range<usize> {
+    let start = buf.as_ptr() as usize;
+
+    start..start + buf.len()
+}
+
+/// The location of the root buffer that views are parsed from.
+///
+/// Only the address and length of the buffer are recorded, so a [`Root`] can be created before the
+/// buffer is mutably borrowed by views and used while they are alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Root {
+    addr: usize,
+    len: usize,
+}
+
+impl Root {
+    /// Returns a [`Root`] recording the location of `buf`.
+    pub fn new(buf: &[u8]) -> Self {
+        Self {
+            addr: buf.as_ptr() as usize,
+            len: buf.len(),
+        }
+    }
+
+    /// Returns the length of the root buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the root buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `addrs` as a range of offsets from the start of the root buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `addrs` is not within the root buffer
+    fn offsets(&self, addrs: Range<usize>) -> Range<usize> {
+        assert!(
+            addrs.start >= self.addr && addrs.end <= self.addr + self.len,
+            "view is not within the root buffer"
+        );
+
+        addrs.start - self.addr..addrs.end - self.addr
+    }
+}
+
+/// `Option` fields are defaulted rather than parsed by [`MutView`](crate::MutView), so cover no bytes.
+impl<T> ViewOffset for Option<T> {
+    fn addr_range(&self) -> Range<usize> {
+        0..0
+    }
+}
+
+/// Trait for views and structs that can report where they are in the root buffer.
+///
+/// Implemented by every `zordon` view. Structs implement it with
+/// [`impl_view_offset`](crate::impl_view_offset).
+///
+/// Views only hold the slice they were split from, so offsets are not stored in them. Instead the
+/// location of the root buffer is recorded in a [`Root`] before parsing, and each offset is worked
+/// out from the address of the view's slice. The [`Root`] must be kept alongside the views and passed
+/// to [`ViewOffset::offset`] and [`ViewOffset::range`].
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, MulByteView, LitEnd, Root, ViewOffset};
+///
+/// #[derive(MutView)]
+/// struct Header<'a> {
+///     kind: ByteView<'a, u8>,
+///     len: MulByteView<'a, u16, LitEnd>,
+/// }
+///
+/// let mut input_buf = [0xFF, 0x01, 0x02, 0x00];
+/// let root = Root::new(&input_buf);
+/// let (_, buf) = input_buf.split_at_mut(1);
+/// let (header, _) = Header::mut_view(buf);
+///
+/// assert_eq!(header.len.offset(root), 2);
+/// assert_eq!(header.len.range(root), 2..4);
+/// ```
+pub trait ViewOffset {
+    /// Returns the range of addresses covered by the view.
+    fn addr_range(&self) -> Range<usize>;
+
+    /// Returns the offset of the view from the start of `root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the view was not parsed from `root`
+    fn offset(&self, root: Root) -> usize {
+        self.range(root).start
+    }
+
+    /// Returns the range of bytes covered by the view, relative to the start of `root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the view was not parsed from `root`
+    fn range(&self, root: Root) -> Range<usize> {
+        root.offsets(self.addr_range())
+    }
+}
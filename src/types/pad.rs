@@ -0,0 +1,238 @@
+//! Views of padding, alignment and reserved bytes that are skipped over when parsing.
+
+use super::{addr_range, check_len, ViewOffset, ViewSize, ZordonView};
+use crate::error::{Error, Result};
+
+/// A view that consumes N bytes of padding and provides no access to them.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, PadView, ModByteView};
+///
+/// #[derive(MutView)]
+/// struct Header<'a> {
+///     kind: ByteView<'a, u8>,
+///     _pad: PadView<'a, 3>,
+///     id: ByteView<'a, u8>,
+/// }
+///
+/// let mut input_buf = [0x01, 0xAA, 0xBB, 0xCC, 0x02];
+/// let (header, _) = Header::mut_view(&mut input_buf);
+///
+/// assert_eq!(header.kind.val(), 0x01);
+/// assert_eq!(header.id.val(), 0x02);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct PadView<'a, const N: usize> {
+    buf: &'a mut [u8],
+}
+
+impl<'a, const N: usize> PadView<'a, N> {
+    /// Returns a [`PadView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(N);
+
+        (Self { buf }, leftover)
+    }
+
+    /// Fallible version of [`PadView::mut_view`]. Returns a [`PadView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `N > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, N)?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, const N: usize> ViewSize for PadView<'a, N> {
+    const SIZE: usize = N;
+}
+
+impl<'a, const N: usize> ZordonView<'a> for PadView<'a, N> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        PadView::mut_view(arr)
+    }
+}
+
+impl<'a, const N: usize> ViewOffset for PadView<'a, N> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+/// A view that consumes bytes up to the next multiple of A, counted from the start of a struct.
+///
+/// As a view only sees the slice left to parse, the number of bytes already parsed since the start
+/// of the struct is passed in as `pos`. Neither the [`MutView`](crate::MutView) derive nor
+/// [`impl_try_mut_view!`](crate::impl_try_mut_view) tracks this, so [`AlignView`] implements neither
+/// [`ZordonView`] nor [`ViewSize`] and cannot be a field of a derived struct. It only works when
+/// parsing by hand, constructed with [`AlignView::mut_view_at`] after the fields before it.
+///
+/// ```
+/// use zordon::types::{AlignView, ArrayView, ByteView, ModByteView};
+///
+/// let mut input_buf = [0x01, 0x02, 0x03, 0xAA, 0x04];
+/// let (_name, buf) = ArrayView::<[u8; 3]>::mut_view(&mut input_buf);
+/// let (pad, buf) = AlignView::<4>::mut_view_at(buf, 3);
+/// let (id, _): (ByteView<u8>, _) = ByteView::mut_view(buf);
+///
+/// assert_eq!(pad.len(), 1);
+/// assert_eq!(id.val(), 0x04);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct AlignView<'a, const A: usize> {
+    buf: &'a mut [u8],
+}
+
+impl<'a, const A: usize> AlignView<'a, A> {
+    /// Returns the number of padding bytes needed to align `pos` to a multiple of A.
+    pub const fn padding(pos: usize) -> usize {
+        (A - pos % A) % A
+    }
+
+    /// Returns an [`AlignView`] of the padding after `pos` bytes of the struct and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A == 0` or the padding is longer than `arr`
+    pub fn mut_view_at(arr: &'a mut [u8], pos: usize) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(Self::padding(pos));
+
+        (Self { buf }, leftover)
+    }
+
+    /// Fallible version of [`AlignView::mut_view_at`]. Returns an [`AlignView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the padding is longer than `arr`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A == 0`
+    pub fn try_mut_view_at(arr: &'a mut [u8], pos: usize) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, Self::padding(pos))?;
+
+        Ok(Self::mut_view_at(arr, pos))
+    }
+
+    /// Returns the number of padding bytes consumed.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns true if no padding was needed.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+}
+
+impl<'a, const A: usize> ViewOffset for AlignView<'a, A> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+/// A view of a reserved field the size of T, which should be zero.
+///
+/// Parsing does not check the reserved bytes, so that files written by producers that reuse them
+/// can still be read. Use [`ReservedView::verify`] to check them and [`ReservedView::clear`] to
+/// zero them.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, ReservedView};
+///
+/// #[derive(MutView)]
+/// struct Header<'a> {
+///     kind: ByteView<'a, u8>,
+///     reserved: ReservedView<'a, u16>,
+/// }
+///
+/// let mut input_buf = [0x01, 0x00, 0x02];
+/// let (mut header, _) = Header::mut_view(&mut input_buf);
+///
+/// assert!(header.reserved.verify().is_err());
+/// header.reserved.clear();
+/// assert!(header.reserved.verify().is_ok());
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ReservedView<'a, T> {
+    buf: &'a mut [u8],
+    _type: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> ReservedView<'a, T> {
+    /// Returns a [`ReservedView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(std::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: std::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`ReservedView::mut_view`]. Returns a [`ReservedView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `T.len() > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::mut_view(arr))
+    }
+
+    /// Returns true if every reserved byte is zero.
+    pub fn is_zero(&self) -> bool {
+        self.buf.iter().all(|&b| b == 0)
+    }
+
+    /// Checks that every reserved byte is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] with the offset of the first non-zero byte from the start of the
+    /// view
+    pub fn verify(&self) -> Result<()> {
+        match self.buf.iter().position(|&b| b != 0) {
+            Some(offset) => Err(Error::Invalid {
+                reason: "reserved bytes are not zero",
+                offset,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets every reserved byte to zero.
+    pub fn clear(&mut self) {
+        self.buf.fill(0)
+    }
+}
+
+impl<'a, T> ViewSize for ReservedView<'a, T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+crate::impl_zordon_view!(ReservedView<T>);
+
+impl<'a, T> ViewOffset for ReservedView<'a, T> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
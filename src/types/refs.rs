@@ -0,0 +1,544 @@
+//! Read-only counterparts of the mutable `zordon` views.
+
+use super::{
+    addr_range, check_len, ArrayView, BigEnd, ByteView, Endian, LitEnd, MulByteView, NativeEnd,
+    Primitive, SetEndian, ViewOffset, ViewSize,
+};
+use crate::error::Result;
+use byteorder::{BigEndian, ByteOrder, LittleEndian, NativeEndian};
+use std::convert::TryInto;
+
+/// For getting single byte values from a read-only view.
+pub trait RefByteView<'a, T> {
+    /// Return a copy of the underlying value T.
+    fn val(&self) -> T;
+}
+
+/// For getting multi byte values from a read-only view.
+pub trait RefMulByteView<'a, T, E> {
+    /// Return a copy of the underlying value T.
+    fn val(&self) -> T;
+}
+
+/// Read-only counterpart of [`ZordonView`](super::ZordonView), implemented by the read-only views.
+///
+/// Implementing it for a custom type allows the type to be used as a field of a struct wrapped by
+/// [`impl_ref_view`](crate::impl_ref_view), just like the built-in types.
+pub trait ZordonRef<'a>: ViewSize + Sized {
+    /// Returns the view and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::SIZE > arr.len()`
+    fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]);
+
+    /// Fallible version of [`ZordonRef::ref_view`]. Returns the view and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `Self::SIZE > arr.len()`
+    fn try_ref_view(arr: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        check_len(arr, Self::SIZE)?;
+
+        Ok(Self::ref_view(arr))
+    }
+}
+
+/// `Option` fields are set to `None` without consuming any bytes, as in [`MutView`](crate::MutView).
+impl<'a, T> ZordonRef<'a> for Option<T> {
+    fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        (None, arr)
+    }
+}
+
+/// A read-only byte view for type T where the length of the view is always 1.
+///
+/// Check [`RefByteView`] implementations for valid T monomorphisms.
+#[derive(Debug, PartialEq)]
+pub struct ByteRef<'a, T> {
+    val: &'a [u8],
+    _type: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> ByteRef<'a, T> {
+    /// Constructs a new [`ByteRef`] and returns the leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (val, leftover) = arr.split_at(std::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`ByteRef::ref_view`]. Returns a [`ByteRef`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `T.len() > arr.len()`
+    pub fn try_ref_view(arr: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::ref_view(arr))
+    }
+}
+
+impl<'a, T> ViewSize for ByteRef<'a, T> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl<'a, T> ZordonRef<'a> for ByteRef<'a, T> {
+    fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        ByteRef::ref_view(arr)
+    }
+}
+
+impl<'a, T> ViewOffset for ByteRef<'a, T> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T> Clone for ByteRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for ByteRef<'a, T> {}
+
+/// Template for implementing RefByteView<'a, _>
+#[macro_export]
+macro_rules! impl_refbyteval {
+    ($target:tt, $bytesized_type:tt) => {
+        impl<'a> RefByteView<'a, $bytesized_type> for $target<'a, $bytesized_type> {
+            fn val(&self) -> $bytesized_type {
+                self.val[0] as $bytesized_type
+            }
+        }
+    };
+}
+
+impl_refbyteval!(ByteRef, u8);
+impl_refbyteval!(ByteRef, i8);
+
+/// A read-only multi byte view for type T where the length of the view varies depending on T.
+///
+/// For valid types for T, check [`RefMulByteView`] implementations.
+#[derive(Debug, PartialEq)]
+pub struct MulByteRef<'a, T, E> {
+    val: &'a [u8],
+    _type: std::marker::PhantomData<T>,
+    endian: E,
+}
+
+impl<'a, T, E: Default> MulByteRef<'a, T, E> {
+    /// Returns a [`MulByteRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (val, leftover) = arr.split_at(std::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: std::marker::PhantomData::<T>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`MulByteRef::ref_view`]. Returns a [`MulByteRef`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `T.len() > arr.len()`
+    pub fn try_ref_view(arr: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::ref_view(arr))
+    }
+}
+
+impl<'a, T, E> ViewSize for MulByteRef<'a, T, E> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl<'a, T, E: Default> ZordonRef<'a> for MulByteRef<'a, T, E> {
+    fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        MulByteRef::ref_view(arr)
+    }
+}
+
+impl<'a, T, E> ViewOffset for MulByteRef<'a, T, E> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.val)
+    }
+}
+
+impl<'a, T, E: Clone> Clone for MulByteRef<'a, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            val: self.val,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian.clone(),
+        }
+    }
+}
+
+impl<'a, T, E: Copy> Copy for MulByteRef<'a, T, E> {}
+
+/// Template for implementing RefMulByteView<'a, _, _>.
+#[macro_export]
+macro_rules! impl_refmulbyteval {
+    ($target:tt, $type:tt, $endian:tt, $endianident:ident, $read:ident) => {
+        impl<'a> RefMulByteView<'a, $type, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> $type {
+                $endianident::$read(self.val)
+            }
+        }
+    };
+}
+
+impl_refmulbyteval!(MulByteRef, u16, LitEnd, LittleEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u16, BigEnd, BigEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u32, LitEnd, LittleEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u32, BigEnd, BigEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u64, LitEnd, LittleEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u64, BigEnd, BigEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u128, LitEnd, LittleEndian, read_u128);
+impl_refmulbyteval!(MulByteRef, u128, BigEnd, BigEndian, read_u128);
+
+impl_refmulbyteval!(MulByteRef, i16, LitEnd, LittleEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i16, BigEnd, BigEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i32, LitEnd, LittleEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i32, BigEnd, BigEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i64, LitEnd, LittleEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i64, BigEnd, BigEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i128, LitEnd, LittleEndian, read_i128);
+impl_refmulbyteval!(MulByteRef, i128, BigEnd, BigEndian, read_i128);
+
+impl_refmulbyteval!(MulByteRef, u16, NativeEnd, NativeEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u32, NativeEnd, NativeEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u64, NativeEnd, NativeEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u128, NativeEnd, NativeEndian, read_u128);
+impl_refmulbyteval!(MulByteRef, i16, NativeEnd, NativeEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i32, NativeEnd, NativeEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i64, NativeEnd, NativeEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i128, NativeEnd, NativeEndian, read_i128);
+
+impl_refmulbyteval!(MulByteRef, f32, LitEnd, LittleEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f32, BigEnd, BigEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f64, LitEnd, LittleEndian, read_f64);
+impl_refmulbyteval!(MulByteRef, f64, BigEnd, BigEndian, read_f64);
+impl_refmulbyteval!(MulByteRef, f32, NativeEnd, NativeEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f64, NativeEnd, NativeEndian, read_f64);
+
+impl<'a, T> MulByteRef<'a, T, Endian> {
+    /// Returns a [`MulByteRef`] using the byte order `endian` and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn ref_view_endian(arr: &'a [u8], endian: Endian) -> (Self, &'a [u8]) {
+        let (mut view, leftover) = Self::ref_view(arr);
+        view.endian = endian;
+
+        (view, leftover)
+    }
+
+    /// Fallible version of [`MulByteRef::ref_view_endian`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `T.len() > arr.len()`
+    pub fn try_ref_view_endian(arr: &'a [u8], endian: Endian) -> Result<(Self, &'a [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::ref_view_endian(arr, endian))
+    }
+
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T> SetEndian for MulByteRef<'a, T, Endian> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+/// Template for implementing RefMulByteView<'a, _, Endian>.
+#[macro_export]
+macro_rules! impl_refmulbyteval_endian {
+    ($target:tt, $type:tt) => {
+        impl<'a> RefMulByteView<'a, $type, Endian> for $target<'a, $type, Endian> {
+            fn val(&self) -> $type {
+                $type::read(self.val, self.endian)
+            }
+        }
+    };
+}
+
+impl_refmulbyteval_endian!(MulByteRef, u16);
+impl_refmulbyteval_endian!(MulByteRef, u32);
+impl_refmulbyteval_endian!(MulByteRef, u64);
+impl_refmulbyteval_endian!(MulByteRef, u128);
+impl_refmulbyteval_endian!(MulByteRef, i16);
+impl_refmulbyteval_endian!(MulByteRef, i32);
+impl_refmulbyteval_endian!(MulByteRef, i64);
+impl_refmulbyteval_endian!(MulByteRef, i128);
+impl_refmulbyteval_endian!(MulByteRef, f32);
+impl_refmulbyteval_endian!(MulByteRef, f64);
+
+/// A read-only array view for type [T; L] where L is a const.
+///
+/// For [u8; L] the array is accessed by reference. For arrays of other primitive types, elements are
+/// accessed by index and E is an endian marker specifying the byte order of each element.
+#[derive(Debug, PartialEq)]
+pub struct ArrayRef<'a, T, E = LitEnd> {
+    pub(super) buf: &'a [u8],
+    _type: std::marker::PhantomData<T>,
+    pub(super) endian: E,
+}
+
+impl<'a, T, E: Default> ArrayRef<'a, T, E> {
+    /// Returns an [`ArrayRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (buf, leftover) = arr.split_at(std::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: std::marker::PhantomData::<T>,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`ArrayRef::ref_view`]. Returns an [`ArrayRef`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `T.len() > arr.len()`
+    pub fn try_ref_view(arr: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        check_len(arr, std::mem::size_of::<T>())?;
+
+        Ok(Self::ref_view(arr))
+    }
+}
+
+impl<'a, T, E> ViewSize for ArrayRef<'a, T, E> {
+    const SIZE: usize = std::mem::size_of::<T>();
+}
+
+impl<'a, T, E: Default> ZordonRef<'a> for ArrayRef<'a, T, E> {
+    fn ref_view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        ArrayRef::ref_view(arr)
+    }
+}
+
+impl<'a, T, E> ViewOffset for ArrayRef<'a, T, E> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+impl<'a, T, E: Clone> Clone for ArrayRef<'a, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian.clone(),
+        }
+    }
+}
+
+impl<'a, T, E: Copy> Copy for ArrayRef<'a, T, E> {}
+
+impl<'a, E, const L: usize> ArrayRef<'a, [u8; L], E> {
+    /// Returns a reference to the array.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &'a [u8; L] {
+        // The length of buf is always L, as it was split at size_of::<[u8; L]>().
+        self.buf.try_into().unwrap()
+    }
+}
+
+impl<'a, T> ArrayRef<'a, T, Endian> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, T> SetEndian for ArrayRef<'a, T, Endian> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+impl<'a, T> ByteView<'a, T> {
+    /// Returns a [`ByteRef`] reborrowing the underlying slice.
+    pub fn downgrade(&self) -> ByteRef<'_, T> {
+        ByteRef {
+            val: self.val,
+            _type: std::marker::PhantomData::<T>,
+        }
+    }
+
+    /// Converts the view into a [`ByteRef`] over the same slice.
+    pub fn into_ref(self) -> ByteRef<'a, T> {
+        ByteRef {
+            val: self.val,
+            _type: std::marker::PhantomData::<T>,
+        }
+    }
+}
+
+impl<'a, T, E: Clone> MulByteView<'a, T, E> {
+    /// Returns a [`MulByteRef`] reborrowing the underlying slice.
+    pub fn downgrade(&self) -> MulByteRef<'_, T, E> {
+        MulByteRef {
+            val: self.val,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian.clone(),
+        }
+    }
+
+    /// Converts the view into a [`MulByteRef`] over the same slice.
+    pub fn into_ref(self) -> MulByteRef<'a, T, E> {
+        MulByteRef {
+            val: self.val,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian,
+        }
+    }
+}
+
+impl<'a, T, E: Clone> ArrayView<'a, T, E> {
+    /// Returns an [`ArrayRef`] reborrowing the underlying slice.
+    pub fn downgrade(&self) -> ArrayRef<'_, T, E> {
+        ArrayRef {
+            buf: self.buf,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian.clone(),
+        }
+    }
+
+    /// Converts the view into an [`ArrayRef`] over the same slice.
+    pub fn into_ref(self) -> ArrayRef<'a, T, E> {
+        ArrayRef {
+            buf: self.buf,
+            _type: std::marker::PhantomData::<T>,
+            endian: self.endian,
+        }
+    }
+}
+
+/// Template for implementing `ref_view` on a struct composed of read-only `zordon` types.
+///
+/// Wraps the struct definition, which is emitted unchanged, so the fields are parsed in the order and
+/// with the types they are declared with. Every field type must have a `ref_view` and `try_ref_view`
+/// method or implement [`ZordonRef`]. Both `ref_view` and a fallible `try_ref_view` are implemented,
+/// so structs using this macro can themselves be nested. `Option` fields are set to `None` without
+/// consuming any bytes.
+///
+/// ```
+/// use zordon::impl_ref_view;
+/// use zordon::types::{ByteRef, MulByteRef, BigEnd, RefByteView, RefMulByteView};
+///
+/// impl_ref_view! {
+///     struct Example<'a> {
+///         u8_f: ByteRef<'a, u8>,
+///         u16_f: MulByteRef<'a, u16, BigEnd>,
+///     }
+/// }
+///
+/// static INPUT: [u8; 3] = [0x00, 0x01, 0x02];
+/// let (example, _) = Example::ref_view(&INPUT);
+///
+/// assert_eq!(example.u8_f.val(), 0x00);
+/// assert_eq!(example.u16_f.val(), 0x0102);
+/// ```
+#[macro_export]
+macro_rules! impl_ref_view {
+    (@impl $mac:ident ! { $($inner:tt)* }) => {
+        $crate::impl_ref_view!(@impl $($inner)*);
+    };
+    (
+        @impl
+        $(#[$attr:meta])*
+        $vis:vis struct $target:ident<$lt:lifetime> {
+            $($(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        impl<$lt> $target<$lt> {
+            /// Returns Self and leftover slice.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slice is too short.
+            pub fn ref_view(buf: &$lt [u8]) -> (Self, &$lt [u8]) {
+                #[allow(unused_imports)]
+                use $crate::types::ZordonRef as _;
+
+                $(
+                    let ($field, buf) = <$ty>::ref_view(buf);
+                )*
+
+                (Self { $($field,)* }, buf)
+            }
+
+            /// Fallible version of `ref_view`. Returns Self and leftover slice.
+            pub fn try_ref_view(buf: &$lt [u8]) -> $crate::Result<(Self, &$lt [u8])> {
+                #[allow(unused_imports)]
+                use $crate::types::ZordonRef as _;
+
+                let len = buf.len();
+
+                $(
+                    let offset = len - buf.len();
+                    let ($field, buf) = <$ty>::try_ref_view(buf).map_err(|e| e.offset_by(offset))?;
+                )*
+
+                Ok((Self { $($field,)* }, buf))
+            }
+        }
+    };
+    ($mac:ident ! { $($inner:tt)* }) => {
+        $crate::$mac! { $($inner)* }
+        $crate::impl_ref_view!(@impl $($inner)*);
+    };
+    ($($def:tt)*) => {
+        $($def)*
+        $crate::impl_ref_view!(@impl $($def)*);
+    };
+}
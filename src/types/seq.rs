@@ -0,0 +1,286 @@
+//! Views of a number of records stored back to back.
+
+use super::{addr_range, check_len, LengthField, ViewOffset, ZordonView};
+use crate::error::{Error, Result};
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// A record of a [`SeqView`], which is only constructed the first time it is accessed.
+///
+/// The lazy state is kept in thread-safe cells, so that [`SeqView`] stays `Send` and `Sync` like the
+/// other views.
+struct Slot<'a, V> {
+    raw: Mutex<&'a mut [u8]>,
+    view: OnceLock<V>,
+}
+
+impl<'a, V> Slot<'a, V> {
+    fn new(raw: &'a mut [u8]) -> Self {
+        Self {
+            raw: Mutex::new(raw),
+            view: OnceLock::new(),
+        }
+    }
+
+    /// Takes the bytes of the record, leaving an empty slice behind.
+    fn take_raw(&self) -> &'a mut [u8] {
+        std::mem::take(&mut *self.raw.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns the view, constructing it with `ctor` if needed.
+    fn get(&self, ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8])) -> &V {
+        self.view.get_or_init(|| ctor(self.take_raw()).0)
+    }
+
+    /// Returns the view mutably, constructing it with `ctor` if needed.
+    fn get_mut(&mut self, ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8])) -> &mut V {
+        self.get(ctor);
+
+        match self.view.get_mut() {
+            Some(v) => v,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the view, constructing it with `ctor` if needed.
+    fn into_view(self, ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8])) -> V {
+        match self.view.into_inner() {
+            Some(v) => v,
+            None => {
+                ctor(
+                    self.raw
+                        .into_inner()
+                        .unwrap_or_else(PoisonError::into_inner),
+                )
+                .0
+            }
+        }
+    }
+}
+
+impl<'a, V: fmt::Debug> fmt::Debug for Slot<'a, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.view.get() {
+            Some(v) => f.debug_tuple("View").field(v).finish(),
+            None => f.write_str("Raw"),
+        }
+    }
+}
+
+/// A mutable view of `count` consecutive records of `stride` bytes, each viewed as V.
+///
+/// Each record is constructed with `ctor`, typically the `mut_view` method of a struct deriving
+/// [`MutView`](crate::MutView), the first time it is accessed. The count is either passed directly
+/// or taken from an already parsed field implementing [`LengthField`].
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, MulByteView, SeqView, LitEnd, ModByteView, ModMulByteView};
+///
+/// #[derive(MutView)]
+/// struct Section<'a> {
+///     id: ByteView<'a, u8>,
+///     rva: MulByteView<'a, u16, LitEnd>,
+/// }
+///
+/// let mut input_buf = [0x02, 0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0xFF];
+/// let (count, buf): (ByteView<u8>, _) = ByteView::mut_view(&mut input_buf);
+/// let (mut sections, leftover) = SeqView::mut_view_from(buf, &count, 3, Section::mut_view);
+///
+/// assert_eq!(sections.len(), 2);
+/// assert_eq!(sections.get(1).unwrap().rva.val(), 0x2000);
+///
+/// for s in sections.iter_mut() {
+///     s.rva += 1;
+/// }
+///
+/// assert_eq!(leftover, [0xFF]);
+/// assert_eq!(input_buf, [0x02, 0x01, 0x01, 0x10, 0x02, 0x01, 0x20, 0xFF]);
+/// ```
+#[derive(Debug)]
+pub struct SeqView<'a, V> {
+    slots: Vec<Slot<'a, V>>,
+    ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+    span: Range<usize>,
+}
+
+impl<'a, V> SeqView<'a, V> {
+    /// Returns a [`SeqView`] of `count` records of `stride` bytes and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero, or `count * stride > arr.len()`. Accessing a record panics if
+    /// `ctor` panics because `stride` is too short for the view.
+    pub fn mut_view(
+        arr: &'a mut [u8],
+        count: usize,
+        stride: usize,
+        ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+    ) -> (Self, &'a mut [u8]) {
+        assert!(stride != 0, "record stride is zero");
+
+        let len = count
+            .checked_mul(stride)
+            .expect("record count times stride overflows usize");
+        let (records, leftover) = arr.split_at_mut(len);
+        let span = addr_range(records);
+        let slots = records.chunks_exact_mut(stride).map(Slot::new).collect();
+
+        (Self { slots, ctor, span }, leftover)
+    }
+
+    /// Fallible version of [`SeqView::mut_view`]. Returns a [`SeqView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] if `stride` is zero, or [`Error::OutOfBounds`] if
+    /// `count * stride > arr.len()`
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        count: usize,
+        stride: usize,
+        ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+    ) -> Result<(Self, &'a mut [u8])> {
+        if stride == 0 {
+            return Err(Error::Invalid {
+                reason: "record stride is zero",
+                offset: 0,
+            });
+        }
+
+        check_len(arr, count.saturating_mul(stride))?;
+
+        Ok(Self::mut_view(arr, count, stride, ctor))
+    }
+
+    /// Returns a [`SeqView`] whose count is the value of `field` and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero, or `field.length() * stride > arr.len()`
+    pub fn mut_view_from<L: LengthField>(
+        arr: &'a mut [u8],
+        field: &L,
+        stride: usize,
+        ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+    ) -> (Self, &'a mut [u8]) {
+        Self::mut_view(arr, field.length(), stride, ctor)
+    }
+
+    /// Fallible version of [`SeqView::mut_view_from`]. Returns a [`SeqView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] if `stride` is zero, or [`Error::OutOfBounds`] if
+    /// `field.length() * stride > arr.len()`
+    pub fn try_mut_view_from<L: LengthField>(
+        arr: &'a mut [u8],
+        field: &L,
+        stride: usize,
+        ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+    ) -> Result<(Self, &'a mut [u8])> {
+        Self::try_mut_view(arr, field.length(), stride, ctor)
+    }
+
+    /// Returns a [`SeqView`] of `count` records of V, using [`ZordonView`] to size and construct them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `V::SIZE` is zero, or `count * V::SIZE > arr.len()`
+    pub fn records(arr: &'a mut [u8], count: usize) -> (Self, &'a mut [u8])
+    where
+        V: ZordonView<'a>,
+    {
+        Self::mut_view(arr, count, V::SIZE, <V as ZordonView<'a>>::mut_view)
+    }
+
+    /// Fallible version of [`SeqView::records`]. Returns a [`SeqView`] and leftover slice.
+    ///
+    /// Every record is constructed up front with [`ZordonView::try_mut_view`], so that it is
+    /// validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] if `V::SIZE` is zero, [`Error::OutOfBounds`] if
+    /// `count * V::SIZE > arr.len()`, or the first error returned by [`ZordonView::validate`], with
+    /// an offset relative to the start of `arr`
+    pub fn try_records(arr: &'a mut [u8], count: usize) -> Result<(Self, &'a mut [u8])>
+    where
+        V: ZordonView<'a>,
+    {
+        let (mut seq, leftover) =
+            Self::try_mut_view(arr, count, V::SIZE, <V as ZordonView<'a>>::mut_view)?;
+
+        for (i, slot) in seq.slots.iter_mut().enumerate() {
+            let (view, _) =
+                V::try_mut_view(slot.take_raw()).map_err(|e| e.offset_by(i * V::SIZE))?;
+            slot.view = OnceLock::from(view);
+        }
+
+        Ok((seq, leftover))
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns a reference to the record at index i, or `None` if i is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&V> {
+        self.slots.get(i).map(|slot| slot.get(self.ctor))
+    }
+
+    /// Returns a mutable reference to the record at index i, or `None` if i is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut V> {
+        let ctor = self.ctor;
+
+        self.slots.get_mut(i).map(|slot| slot.get_mut(ctor))
+    }
+
+    /// Returns an iterator over mutable references to the records.
+    pub fn iter_mut(&mut self) -> SeqIterMut<'_, 'a, V> {
+        SeqIterMut {
+            slots: self.slots.iter_mut(),
+            ctor: self.ctor,
+        }
+    }
+
+    /// Converts the view into a `Vec` of every record.
+    pub fn into_vec(self) -> Vec<V> {
+        let ctor = self.ctor;
+
+        self.slots
+            .into_iter()
+            .map(|slot| slot.into_view(ctor))
+            .collect()
+    }
+}
+
+impl<'a, V> ViewOffset for SeqView<'a, V> {
+    fn addr_range(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+/// Iterator over mutable references to the records of a [`SeqView`].
+#[derive(Debug)]
+pub struct SeqIterMut<'s, 'a, V> {
+    slots: std::slice::IterMut<'s, Slot<'a, V>>,
+    ctor: fn(&'a mut [u8]) -> (V, &'a mut [u8]),
+}
+
+impl<'s, 'a, V> Iterator for SeqIterMut<'s, 'a, V> {
+    type Item = &'s mut V;
+
+    fn next(&mut self) -> Option<&'s mut V> {
+        let ctor = self.ctor;
+
+        self.slots.next().map(|slot| slot.get_mut(ctor))
+    }
+}
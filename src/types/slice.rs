@@ -0,0 +1,164 @@
+//! Views whose length is only known at runtime.
+
+use super::{
+    addr_range, check_len, ByteRef, ByteView, Endianness, IntView, ModByteView, ModMulByteView,
+    MulByteRef, MulByteView, RefByteView, RefMulByteView, ViewOffset,
+};
+use crate::error::Result;
+use std::convert::TryInto;
+
+/// Implemented by views holding a value that can be used as the length of a [`SliceView`].
+pub trait LengthField {
+    /// Returns the value as a length. Values that do not fit in a `usize`, such as negative
+    /// values, return `usize::MAX`.
+    fn length(&self) -> usize;
+}
+
+/// Template for implementing LengthField.
+#[macro_export]
+macro_rules! impl_lengthfield {
+    ($target:ident, $trait:ident) => {
+        impl<'a, T> LengthField for $target<'a, T>
+        where
+            $target<'a, T>: $trait<'a, T>,
+            T: TryInto<usize>,
+        {
+            fn length(&self) -> usize {
+                self.val().try_into().unwrap_or(usize::MAX)
+            }
+        }
+    };
+    ($target:ident, $trait:ident, E) => {
+        impl<'a, T, E> LengthField for $target<'a, T, E>
+        where
+            $target<'a, T, E>: $trait<'a, T, E>,
+            T: TryInto<usize>,
+        {
+            fn length(&self) -> usize {
+                self.val().try_into().unwrap_or(usize::MAX)
+            }
+        }
+    };
+    ($target:ident, N, $($type:ty),+) => {
+        $(
+            impl<'a, E: Endianness, const N: usize> LengthField for $target<'a, $type, E, N> {
+                fn length(&self) -> usize {
+                    self.val().try_into().unwrap_or(usize::MAX)
+                }
+            }
+        )+
+    };
+}
+
+impl_lengthfield!(ByteView, ModByteView);
+impl_lengthfield!(ByteRef, RefByteView);
+impl_lengthfield!(MulByteView, ModMulByteView, E);
+impl_lengthfield!(MulByteRef, RefMulByteView, E);
+impl_lengthfield!(IntView, N, u16, u32, u64, u128, i16, i32, i64, i128);
+
+/// A mutable view of a byte slice whose length is chosen at runtime.
+///
+/// The length is either passed directly or taken from an already parsed field implementing
+/// [`LengthField`].
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{MulByteView, SliceView, BigEnd};
+///
+/// #[derive(MutView)]
+/// struct Header<'a> {
+///     len: MulByteView<'a, u16, BigEnd>,
+/// }
+///
+/// let mut input_buf = [0x00, 0x03, 0xAA, 0xBB, 0xCC, 0xDD];
+/// let (header, buf) = Header::mut_view(&mut input_buf);
+/// let (mut payload, leftover) = SliceView::mut_view_from(buf, &header.len);
+///
+/// assert_eq!(payload.as_ref(), [0xAA, 0xBB, 0xCC]);
+/// assert_eq!(leftover, [0xDD]);
+///
+/// payload.as_mut()[0] = 0xFF;
+/// assert_eq!(input_buf[2], 0xFF);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct SliceView<'a> {
+    buf: &'a mut [u8],
+}
+
+impl<'a> SliceView<'a> {
+    /// Returns a [`SliceView`] of `len` bytes and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8], len: usize) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(len);
+
+        (Self { buf }, leftover)
+    }
+
+    /// Fallible version of [`SliceView::mut_view`]. Returns a [`SliceView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `len > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8], len: usize) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, len)?;
+
+        Ok(Self::mut_view(arr, len))
+    }
+
+    /// Returns a [`SliceView`] whose length is the value of `field` and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field.length() > arr.len()`
+    pub fn mut_view_from<L: LengthField>(arr: &'a mut [u8], field: &L) -> (Self, &'a mut [u8]) {
+        Self::mut_view(arr, field.length())
+    }
+
+    /// Fallible version of [`SliceView::mut_view_from`]. Returns a [`SliceView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if `field.length() > arr.len()`
+    pub fn try_mut_view_from<L: LengthField>(
+        arr: &'a mut [u8],
+        field: &L,
+    ) -> Result<(Self, &'a mut [u8])> {
+        Self::try_mut_view(arr, field.length())
+    }
+
+    /// Returns the length of the slice.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns true if the slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns a reference to the slice.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns a mutable reference to the slice.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_mut(&mut self) -> &mut [u8] {
+        self.buf
+    }
+
+    /// Converts the view into the underlying slice.
+    pub fn into_slice(self) -> &'a mut [u8] {
+        self.buf
+    }
+}
+
+impl<'a> ViewOffset for SliceView<'a> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
@@ -0,0 +1,381 @@
+//! Views of fixed-size text fields, padded with NULs.
+
+use super::{
+    addr_range, check_len, Endian, Endianness, Primitive, SetEndian, ViewOffset, ViewSize,
+    ZordonView,
+};
+use crate::error::{Error, Result};
+
+/// Returns the bytes of `buf` before the first NUL.
+fn trim_nul(buf: &[u8]) -> &[u8] {
+    let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+
+    &buf[..end]
+}
+
+/// Returns an error if `s` contains a NUL, which would end the string early when read back.
+///
+/// The offset is where the NUL would be written in a field holding `s` as UTF-8.
+fn check_nul(s: &str) -> Result<()> {
+    match s.find('\0') {
+        Some(offset) => Err(Error::Invalid {
+            reason: "string contains a NUL",
+            offset,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Writes `bytes` to the start of `buf` and fills the rest with NULs.
+///
+/// `buf` is left untouched if `bytes` is too long.
+fn write_padded(buf: &mut [u8], bytes: &[u8]) -> Result<()> {
+    if bytes.len() > buf.len() {
+        return Err(Error::Overflow {
+            size: buf.len(),
+            offset: 0,
+        });
+    }
+
+    let (s, pad) = buf.split_at_mut(bytes.len());
+    s.copy_from_slice(bytes);
+    pad.fill(0);
+
+    Ok(())
+}
+
+/// A mutable view of an L byte ASCII string, padded with NULs.
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{AsciiView, MulByteView, LitEnd};
+///
+/// #[derive(MutView)]
+/// struct SectionHeader<'a> {
+///     name: AsciiView<'a, 8>,
+///     virtual_size: MulByteView<'a, u32, LitEnd>,
+/// }
+///
+/// let mut input_buf = *b".text\0\0\0\x00\x10\x00\x00";
+/// let (mut header, _) = SectionHeader::mut_view(&mut input_buf);
+/// assert_eq!(header.name.val().unwrap(), ".text");
+///
+/// header.name.set(".rdata").unwrap();
+/// assert!(header.name.set(".too_long").is_err());
+/// assert_eq!(&input_buf[..8], b".rdata\0\0");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct AsciiView<'a, const L: usize> {
+    buf: &'a mut [u8],
+}
+
+impl<'a, const L: usize> AsciiView<'a, L> {
+    /// Returns an [`AsciiView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(L);
+
+        (Self { buf }, leftover)
+    }
+
+    /// Fallible version of [`AsciiView::mut_view`]. Returns an [`AsciiView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `L > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, L)?;
+
+        Ok(Self::mut_view(arr))
+    }
+
+    /// Returns a reference to every byte of the field, including padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns the string up to the first NUL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] at the offset of the first byte that is not ASCII
+    pub fn val(&self) -> Result<&str> {
+        let s = trim_nul(self.buf);
+
+        if let Some(offset) = s.iter().position(|b| !b.is_ascii()) {
+            return Err(Error::Invalid {
+                reason: "invalid ASCII",
+                offset,
+            });
+        }
+
+        // ASCII is always valid UTF-8.
+        Ok(std::str::from_utf8(s).unwrap())
+    }
+
+    /// Writes `s` to the field and pads the rest with NULs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if `s` is longer than L bytes, or [`Error::Invalid`] if `s` is not
+    /// ASCII or contains a NUL. The field is left untouched on error.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        if let Some(offset) = s.bytes().position(|b| !b.is_ascii()) {
+            return Err(Error::Invalid {
+                reason: "invalid ASCII",
+                offset,
+            });
+        }
+
+        check_nul(s)?;
+        write_padded(self.buf, s.as_bytes())
+    }
+}
+
+impl<'a, const L: usize> ViewSize for AsciiView<'a, L> {
+    const SIZE: usize = L;
+}
+
+impl<'a, const L: usize> ZordonView<'a> for AsciiView<'a, L> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        AsciiView::mut_view(arr)
+    }
+}
+
+impl<'a, const L: usize> ViewOffset for AsciiView<'a, L> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+/// A mutable view of an L byte UTF-8 string, padded with NULs.
+///
+/// ```
+/// use zordon::types::Utf8View;
+///
+/// let mut input_buf = *b"caf\xC3\xA9\0\0\0";
+/// let (mut name, _) = Utf8View::<8>::mut_view(&mut input_buf);
+/// assert_eq!(name.val().unwrap(), "café");
+///
+/// name.set("naïve").unwrap();
+/// assert_eq!(input_buf, *b"na\xC3\xAFve\0\0");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Utf8View<'a, const L: usize> {
+    buf: &'a mut [u8],
+}
+
+impl<'a, const L: usize> Utf8View<'a, L> {
+    /// Returns a [`Utf8View`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `L > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(L);
+
+        (Self { buf }, leftover)
+    }
+
+    /// Fallible version of [`Utf8View::mut_view`]. Returns a [`Utf8View`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `L > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, L)?;
+
+        Ok(Self::mut_view(arr))
+    }
+
+    /// Returns a reference to every byte of the field, including padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns the string up to the first NUL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] at the offset of the first byte that is not valid UTF-8
+    pub fn val(&self) -> Result<&str> {
+        std::str::from_utf8(trim_nul(self.buf)).map_err(|e| Error::Invalid {
+            reason: "invalid UTF-8",
+            offset: e.valid_up_to(),
+        })
+    }
+
+    /// Writes `s` to the field and pads the rest with NULs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if `s` is longer than L bytes, or [`Error::Invalid`] if `s`
+    /// contains a NUL. The field is left untouched on error.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        check_nul(s)?;
+        write_padded(self.buf, s.as_bytes())
+    }
+}
+
+impl<'a, const L: usize> ViewSize for Utf8View<'a, L> {
+    const SIZE: usize = L;
+}
+
+impl<'a, const L: usize> ZordonView<'a> for Utf8View<'a, L> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        Utf8View::mut_view(arr)
+    }
+}
+
+impl<'a, const L: usize> ViewOffset for Utf8View<'a, L> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
+
+/// A mutable view of a UTF-16 string of L code units (2 * L bytes), padded with NULs.
+///
+/// Each code unit is stored with byte order E.
+///
+/// ```
+/// use zordon::types::{Utf16View, LitEnd};
+///
+/// let mut input_buf = [b'P', 0x00, b'E', 0x00, 0x00, 0x00];
+/// let (mut name, _) = Utf16View::<LitEnd, 3>::mut_view(&mut input_buf);
+/// assert_eq!(name.val().unwrap(), "PE");
+///
+/// name.set("Hi!").unwrap();
+/// assert_eq!(input_buf, [b'H', 0x00, b'i', 0x00, b'!', 0x00]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Utf16View<'a, E, const L: usize> {
+    buf: &'a mut [u8],
+    endian: E,
+}
+
+impl<'a, E: Default, const L: usize> Utf16View<'a, E, L> {
+    /// Returns a [`Utf16View`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2 * L > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(2 * L);
+
+        (
+            Self {
+                buf,
+                endian: E::default(),
+            },
+            leftover,
+        )
+    }
+
+    /// Fallible version of [`Utf16View::mut_view`]. Returns a [`Utf16View`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `2 * L > arr.len()`
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        check_len(arr, 2 * L)?;
+
+        Ok(Self::mut_view(arr))
+    }
+}
+
+impl<'a, E: Endianness, const L: usize> Utf16View<'a, E, L> {
+    /// Returns the code units up to the first NUL.
+    fn units(&self) -> impl Iterator<Item = u16> + '_ {
+        let endian = self.endian.endian();
+
+        self.buf
+            .chunks_exact(2)
+            .map(move |c| u16::read(c, endian))
+            .take_while(|&u| u != 0)
+    }
+
+    /// Returns the string up to the first NUL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] at the offset of the first unpaired surrogate
+    pub fn val(&self) -> Result<String> {
+        let mut s = String::with_capacity(L);
+        let mut offset = 0;
+
+        for c in std::char::decode_utf16(self.units()) {
+            let c = c.map_err(|_| Error::Invalid {
+                reason: "invalid UTF-16",
+                offset,
+            })?;
+
+            offset += 2 * c.len_utf16();
+            s.push(c);
+        }
+
+        Ok(s)
+    }
+
+    /// Writes `s` to the field and pads the rest with NULs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if `s` is longer than L code units, or [`Error::Invalid`] if `s`
+    /// contains a NUL. The field is left untouched on error.
+    pub fn set(&mut self, s: &str) -> Result<()> {
+        if let Some(i) = s.encode_utf16().position(|u| u == 0) {
+            return Err(Error::Invalid {
+                reason: "string contains a NUL",
+                offset: 2 * i,
+            });
+        }
+
+        let endian = self.endian.endian();
+        let mut bytes = Vec::with_capacity(self.buf.len());
+
+        for u in s.encode_utf16() {
+            let mut c = [0; 2];
+            u16::write(&mut c, endian, u);
+            bytes.extend_from_slice(&c);
+        }
+
+        write_padded(self.buf, &bytes)
+    }
+}
+
+impl<'a, const L: usize> Utf16View<'a, Endian, L> {
+    /// Returns the byte order used by the view.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the byte order used by the view. The underlying data is left untouched.
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian
+    }
+}
+
+impl<'a, const L: usize> SetEndian for Utf16View<'a, Endian, L> {
+    fn set_endian(&mut self, endian: Endian) {
+        Self::set_endian(self, endian)
+    }
+}
+
+impl<'a, E, const L: usize> ViewSize for Utf16View<'a, E, L> {
+    const SIZE: usize = 2 * L;
+}
+
+impl<'a, E: Default, const L: usize> ZordonView<'a> for Utf16View<'a, E, L> {
+    fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        Utf16View::mut_view(arr)
+    }
+}
+
+impl<'a, E, const L: usize> ViewOffset for Utf16View<'a, E, L> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}
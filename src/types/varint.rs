@@ -0,0 +1,306 @@
+//! Views of variable-length integers, whose size depends on the value they hold.
+
+use super::{addr_range, ViewOffset};
+use crate::error::{Error, Result};
+
+/// Maximum number of bytes in a LEB128 encoded 64 bit value.
+const LEB128_MAX_LEN: usize = 10;
+
+/// Trait for markers selecting the encoding of a [`VarIntView`].
+pub trait VarIntFormat {
+    /// The type of the decoded value.
+    type Value: Copy;
+
+    /// Returns the length of the encoded value at the start of `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the value is truncated, or [`Error::Invalid`] if it is
+    /// malformed
+    fn encoded_len(buf: &[u8]) -> Result<usize>;
+
+    /// Decodes the value held in `buf`, which is exactly one encoded value.
+    fn decode(buf: &[u8]) -> Self::Value;
+
+    /// Encodes v into exactly `buf.len()` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if v does not fit, in which case `buf` is left untouched
+    fn encode(v: Self::Value, buf: &mut [u8]) -> Result<()>;
+}
+
+/// Unsigned LEB128, as used by DWARF and WebAssembly, decoded into a [`u64`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Uleb128;
+/// Signed LEB128, as used by DWARF and WebAssembly, decoded into an [`i64`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Sleb128;
+/// QUIC variable-length integers (RFC 9000), holding up to 62 bits in 1, 2, 4 or 8 bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QuicVarInt;
+
+/// Returns the length of the LEB128 value at the start of `buf`.
+///
+/// `last_ok` checks the final byte when all [`LEB128_MAX_LEN`] bytes are used, as only some of its
+/// bits fit in 64 bits.
+fn leb128_len(buf: &[u8], last_ok: fn(u8) -> bool) -> Result<usize> {
+    for (i, &b) in buf.iter().take(LEB128_MAX_LEN).enumerate() {
+        if b & 0x80 == 0 {
+            if i == LEB128_MAX_LEN - 1 && !last_ok(b) {
+                return Err(Error::Invalid {
+                    reason: "varint does not fit in 64 bits",
+                    offset: i,
+                });
+            }
+
+            return Ok(i + 1);
+        }
+    }
+
+    if buf.len() >= LEB128_MAX_LEN {
+        return Err(Error::Invalid {
+            reason: "varint does not fit in 64 bits",
+            offset: LEB128_MAX_LEN - 1,
+        });
+    }
+
+    Err(Error::OutOfBounds {
+        needed: buf.len() + 1,
+        available: buf.len(),
+        offset: 0,
+    })
+}
+
+impl VarIntFormat for Uleb128 {
+    type Value = u64;
+
+    fn encoded_len(buf: &[u8]) -> Result<usize> {
+        leb128_len(buf, |b| b <= 0x01)
+    }
+
+    fn decode(buf: &[u8]) -> u64 {
+        buf.iter()
+            .enumerate()
+            .fold(0, |v, (i, &b)| v | u64::from(b & 0x7F) << (7 * i))
+    }
+
+    fn encode(v: u64, buf: &mut [u8]) -> Result<()> {
+        let mut enc = [0x80; LEB128_MAX_LEN];
+        let mut rest = v;
+
+        for b in enc.iter_mut().take(buf.len()) {
+            *b |= (rest & 0x7F) as u8;
+            rest >>= 7;
+        }
+
+        if rest != 0 || buf.is_empty() || buf.len() > LEB128_MAX_LEN {
+            return Err(Error::Overflow {
+                size: buf.len(),
+                offset: 0,
+            });
+        }
+
+        enc[buf.len() - 1] &= 0x7F;
+        buf.copy_from_slice(&enc[..buf.len()]);
+
+        Ok(())
+    }
+}
+
+impl VarIntFormat for Sleb128 {
+    type Value = i64;
+
+    fn encoded_len(buf: &[u8]) -> Result<usize> {
+        leb128_len(buf, |b| b == 0x00 || b == 0x7F)
+    }
+
+    fn decode(buf: &[u8]) -> i64 {
+        let v = Uleb128::decode(buf) as i64;
+        let shift = 7 * buf.len();
+
+        match buf.last() {
+            Some(b) if shift < 64 && b & 0x40 != 0 => v | -1 << shift,
+            _ => v,
+        }
+    }
+
+    fn encode(v: i64, buf: &mut [u8]) -> Result<()> {
+        let mut enc = [0x80; LEB128_MAX_LEN];
+        let mut rest = v;
+
+        for b in enc.iter_mut().take(buf.len()) {
+            *b |= (rest & 0x7F) as u8;
+            rest >>= 7;
+        }
+
+        let sign_ok = match buf.len().checked_sub(1).map(|i| enc[i] & 0x40 != 0) {
+            Some(neg) => rest == if neg { -1 } else { 0 },
+            None => false,
+        };
+
+        if !sign_ok || buf.len() > LEB128_MAX_LEN {
+            return Err(Error::Overflow {
+                size: buf.len(),
+                offset: 0,
+            });
+        }
+
+        enc[buf.len() - 1] &= 0x7F;
+        buf.copy_from_slice(&enc[..buf.len()]);
+
+        Ok(())
+    }
+}
+
+impl VarIntFormat for QuicVarInt {
+    type Value = u64;
+
+    fn encoded_len(buf: &[u8]) -> Result<usize> {
+        let len = buf.first().map_or(1, |b| 1 << (b >> 6));
+
+        if buf.len() < len {
+            return Err(Error::OutOfBounds {
+                needed: len,
+                available: buf.len(),
+                offset: 0,
+            });
+        }
+
+        Ok(len)
+    }
+
+    fn decode(buf: &[u8]) -> u64 {
+        buf.iter().fold(0, |v, &b| v << 8 | u64::from(b)) & (u64::MAX >> (66 - 8 * buf.len()))
+    }
+
+    fn encode(v: u64, buf: &mut [u8]) -> Result<()> {
+        let prefix = match buf.len() {
+            1 => 0,
+            2 => 1,
+            4 => 2,
+            8 => 3,
+            _ => 4,
+        };
+
+        if prefix > 3 || v > u64::MAX >> (66 - 8 * buf.len()) {
+            return Err(Error::Overflow {
+                size: buf.len(),
+                offset: 0,
+            });
+        }
+
+        let n = buf.len();
+        buf.copy_from_slice(&v.to_be_bytes()[8 - n..]);
+        buf[0] |= prefix << 6;
+
+        Ok(())
+    }
+}
+
+/// A mutable view of a variable-length integer, encoded as given by F.
+///
+/// The length of the view is found from the bytes when it is constructed. As the length is not fixed,
+/// [`ViewSize`](super::ViewSize) and [`ZordonView`](super::ZordonView) are not implemented, but the
+/// view can still be used in structs deriving [`MutView`](crate::MutView).
+///
+/// ```
+/// use zordon::MutView;
+/// use zordon::types::{ByteView, Uleb128View, Sleb128View, ModByteView};
+///
+/// #[derive(MutView)]
+/// struct WasmLimits<'a> {
+///     flags: ByteView<'a, u8>,
+///     min: Uleb128View<'a>,
+///     offset: Sleb128View<'a>,
+/// }
+///
+/// let mut input_buf = [0x00, 0xE5, 0x8E, 0x26, 0x7F, 0xFF];
+/// let (mut limits, leftover) = WasmLimits::mut_view(&mut input_buf);
+///
+/// assert_eq!(limits.min.val(), 624485);
+/// assert_eq!(limits.min.encoded_len(), 3);
+/// assert_eq!(limits.offset.val(), -1);
+/// assert_eq!(leftover, [0xFF]);
+///
+/// limits.min.set(1).unwrap();
+/// assert!(limits.offset.set(64).is_err());
+/// assert_eq!(input_buf, [0x00, 0x81, 0x80, 0x00, 0x7F, 0xFF]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct VarIntView<'a, F> {
+    buf: &'a mut [u8],
+    _format: std::marker::PhantomData<F>,
+}
+
+/// An unsigned LEB128 view.
+pub type Uleb128View<'a> = VarIntView<'a, Uleb128>;
+/// A signed LEB128 view.
+pub type Sleb128View<'a> = VarIntView<'a, Sleb128>;
+/// A protobuf varint view. Protobuf varints are unsigned LEB128 values of at most 10 bytes.
+pub type ProtobufVarIntView<'a> = VarIntView<'a, Uleb128>;
+/// A QUIC variable-length integer view.
+pub type QuicVarIntView<'a> = VarIntView<'a, QuicVarInt>;
+
+impl<'a, F: VarIntFormat> VarIntView<'a, F> {
+    /// Returns a [`VarIntView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `arr` does not start with a complete, valid value
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        match Self::try_mut_view(arr) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Fallible version of [`VarIntView::mut_view`]. Returns a [`VarIntView`] and leftover slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the value is truncated, or [`Error::Invalid`] if it is
+    /// malformed
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8])> {
+        let (buf, leftover) = arr.split_at_mut(F::encoded_len(arr)?);
+
+        Ok((
+            Self {
+                buf,
+                _format: std::marker::PhantomData,
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns the number of bytes used to encode the value.
+    pub fn encoded_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns a reference to the encoded bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Returns the decoded value.
+    pub fn val(&self) -> F::Value {
+        F::decode(self.buf)
+    }
+
+    /// Re-encodes v in place, using the same number of bytes as the current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if v does not fit in [`VarIntView::encoded_len`] bytes, in which
+    /// case the view is left untouched
+    pub fn set(&mut self, v: F::Value) -> Result<()> {
+        F::encode(v, self.buf)
+    }
+}
+
+impl<'a, F> ViewOffset for VarIntView<'a, F> {
+    fn addr_range(&self) -> std::ops::Range<usize> {
+        addr_range(self.buf)
+    }
+}